@@ -0,0 +1,325 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{DependentCounter, Limits, MixedRadixCounter};
+
+const SECONDS_PER_DAY: i128 = 24 * 60 * 60;
+
+/// The limits of a proleptic Gregorian date and time, in the order year, month, day, hour,
+/// minute and second. Months and days start at zero, and there are no leap seconds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Gregorian;
+
+impl Limits<u64> for Gregorian {
+    fn limit(&self, index: usize, prefix: &[u64]) -> u64 {
+        match index {
+            0 => u64::MAX,
+            1 => 12,
+            2 => days_in_month(prefix[0], prefix[1] + 1),
+            3 => 24,
+            _ => 60,
+        }
+    }
+}
+
+/// A date and time in the proleptic Gregorian calendar, from year 0 up to but not including
+/// year [`u64::MAX`], without a time zone.
+///
+/// The elements of the underlying [`DependentCounter`] are the year, month, day, hour, minute
+/// and second, where months and days start at zero.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DateTime {
+    counter: DependentCounter<u64, Gregorian, 6>,
+}
+
+impl DateTime {
+    /// Creates a date and time where `month` and `day` start at one, or returns `None` if any
+    /// value is out of range.
+    pub fn from_ymd_hms(
+        year: u64,
+        month: u64,
+        day: u64,
+        hour: u64,
+        minute: u64,
+        second: u64,
+    ) -> Option<Self> {
+        let elements = [
+            year,
+            month.checked_sub(1)?,
+            day.checked_sub(1)?,
+            hour,
+            minute,
+            second,
+        ];
+        let counter = DependentCounter::try_from_limits_and_elements(Gregorian, elements).ok()?;
+        Some(Self { counter })
+    }
+
+    /// Creates the date and time that is `seconds` after 1970-01-01T00:00:00, or returns `None`
+    /// if it is before year 0.
+    pub fn from_unix_seconds(seconds: i64) -> Option<Self> {
+        let seconds = i128::from(seconds);
+        let time = u64::try_from(seconds.rem_euclid(SECONDS_PER_DAY)).ok()?;
+        let (time, _) = MixedRadixCounter::from_scalar([24, 60, 60], time).ok()?;
+        Self::from_days_and_time(seconds.div_euclid(SECONDS_PER_DAY), &time)
+    }
+
+    /// Returns the number of seconds since 1970-01-01T00:00:00, or `None` if that doesn't fit
+    /// into an `i64`.
+    pub fn to_unix_seconds(&self) -> Option<i64> {
+        let time = i128::from(self.time().to_scalar::<u64>()?);
+        i64::try_from(self.days() * SECONDS_PER_DAY + time).ok()
+    }
+
+    pub fn year(&self) -> u64 {
+        self.counter[0]
+    }
+
+    /// Returns the month, starting at one.
+    pub fn month(&self) -> u64 {
+        self.counter[1] + 1
+    }
+
+    /// Returns the day of the month, starting at one.
+    pub fn day(&self) -> u64 {
+        self.counter[2] + 1
+    }
+
+    pub fn hour(&self) -> u64 {
+        self.counter[3]
+    }
+
+    pub fn minute(&self) -> u64 {
+        self.counter[4]
+    }
+
+    pub fn second(&self) -> u64 {
+        self.counter[5]
+    }
+
+    pub fn counter(&self) -> &DependentCounter<u64, Gregorian, 6> {
+        &self.counter
+    }
+
+    /// Moves one second forward, and returns the carry if the year overflowed.
+    pub fn increment(&mut self) -> Option<u64> {
+        self.counter.increment()
+    }
+
+    /// Moves one second backward, and returns the borrow if the year underflowed.
+    pub fn decrement(&mut self) -> Option<u64> {
+        self.counter.decrement()
+    }
+
+    /// Moves `seconds` forward, unless the year would overflow, in which case `None` is
+    /// returned and the date and time are left unchanged.
+    pub fn checked_add_seconds(&mut self, seconds: u64) -> Option<()> {
+        let mut time = self.time();
+        let carry = time.add(seconds % 86_400).unwrap_or_default();
+        let days = i128::from(seconds / 86_400) + i128::from(carry);
+        *self = Self::from_days_and_time(self.days() + days, &time)?;
+        Some(())
+    }
+
+    /// Moves `seconds` backward, unless the year would underflow, in which case `None` is
+    /// returned and the date and time are left unchanged.
+    pub fn checked_sub_seconds(&mut self, seconds: u64) -> Option<()> {
+        let mut time = self.time();
+        let borrow = time.sub(seconds % 86_400).unwrap_or_default();
+        let days = i128::from(seconds / 86_400) + i128::from(borrow);
+        *self = Self::from_days_and_time(self.days() - days, &time)?;
+        Some(())
+    }
+
+    /// Moves `days` forward, keeping the time of day, unless the year would overflow.
+    pub fn checked_add_days(&mut self, days: u64) -> Option<()> {
+        *self = Self::from_days_and_time(self.days() + i128::from(days), &self.time())?;
+        Some(())
+    }
+
+    /// Moves `days` backward, keeping the time of day, unless the year would underflow.
+    pub fn checked_sub_days(&mut self, days: u64) -> Option<()> {
+        *self = Self::from_days_and_time(self.days() - i128::from(days), &self.time())?;
+        Some(())
+    }
+
+    fn time(&self) -> MixedRadixCounter<u64, 3> {
+        let Ok(time) = MixedRadixCounter::try_from_limits_and_elements(
+            [24, 60, 60],
+            [self.hour(), self.minute(), self.second()],
+        ) else {
+            unreachable!("the counter only contains valid times");
+        };
+        time
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    fn days(&self) -> i128 {
+        days_from_civil(self.year(), self.month(), self.day())
+    }
+
+    fn from_days_and_time(days: i128, time: &MixedRadixCounter<u64, 3>) -> Option<Self> {
+        let (year, month, day) = civil_from_days(days)?;
+        Self::from_ymd_hms(year, month, day, time[0], time[1], time[2])
+    }
+}
+
+/// Formats the date and time according to ISO 8601, e.g. `2023-11-14T22:13:20`.
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+fn is_leap_year(year: u64) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// `month` starts at one.
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The conversions between dates and day numbers are Howard Hinnant's `days_from_civil` and
+// `civil_from_days`, which count in eras of 400 years that start on March 1st, so that leap
+// days are at the end of a year.
+
+fn days_from_civil(year: u64, month: u64, day: u64) -> i128 {
+    let year = i128::from(year) - i128::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i128::from(month);
+    let month_from_march = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_from_march + 2) / 5 + i128::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns `None` if the year doesn't fit into a `u64`.
+fn civil_from_days(days: i128) -> Option<(u64, u64, u64)> {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = era * 400 + year_of_era + i128::from(month <= 2);
+    Some((
+        u64::try_from(year).ok()?,
+        u64::try_from(month).ok()?,
+        u64::try_from(day).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::DateTime;
+
+    #[test]
+    fn test_validation() {
+        assert!(DateTime::from_ymd_hms(2000, 2, 29, 0, 0, 0).is_some());
+        assert!(DateTime::from_ymd_hms(2024, 2, 29, 0, 0, 0).is_some());
+        assert!(DateTime::from_ymd_hms(1900, 2, 29, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 2, 29, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 4, 31, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 13, 1, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 0, 1, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 1, 0, 0, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 1, 1, 24, 0, 0).is_none());
+        assert!(DateTime::from_ymd_hms(2023, 1, 1, 0, 0, 60).is_none());
+    }
+
+    #[test]
+    fn test_unix_seconds() {
+        for (seconds, expected) in [
+            (0, "1970-01-01T00:00:00"),
+            (-1, "1969-12-31T23:59:59"),
+            (951_782_400, "2000-02-29T00:00:00"),
+            (1_700_000_000, "2023-11-14T22:13:20"),
+            (-62_167_219_200, "0000-01-01T00:00:00"),
+        ] {
+            let date_time = DateTime::from_unix_seconds(seconds).unwrap();
+            assert_eq!(date_time.to_string(), expected);
+            assert_eq!(date_time.to_unix_seconds(), Some(seconds));
+        }
+        assert_eq!(DateTime::from_unix_seconds(-62_167_219_201), None);
+
+        for seconds in (-62_167_219_200..i64::MAX / 1000).step_by(99_999_999_977) {
+            let date_time = DateTime::from_unix_seconds(seconds).unwrap();
+            assert_eq!(date_time.to_unix_seconds(), Some(seconds));
+        }
+    }
+
+    #[test]
+    fn test_increment() {
+        let mut date_time = DateTime::from_ymd_hms(2024, 2, 28, 23, 59, 59).unwrap();
+        assert_eq!(date_time.increment(), None);
+        assert_eq!(date_time.to_string(), "2024-02-29T00:00:00");
+        assert_eq!(date_time.decrement(), None);
+        assert_eq!(date_time.to_string(), "2024-02-28T23:59:59");
+
+        let mut date_time = DateTime::from_ymd_hms(2023, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(date_time.increment(), None);
+        assert_eq!(date_time.to_string(), "2024-01-01T00:00:00");
+
+        let mut date_time = DateTime::from_ymd_hms(2024, 1, 1, 23, 59, 59).unwrap();
+        let mut days = 0;
+        while date_time.year() == 2024 {
+            date_time.checked_add_days(1).unwrap();
+            days += 1;
+        }
+        assert_eq!(days, 366);
+        assert_eq!(date_time.to_string(), "2025-01-01T23:59:59");
+    }
+
+    #[test]
+    fn test_add() {
+        let mut date_time = DateTime::from_ymd_hms(2023, 2, 28, 12, 0, 0).unwrap();
+        assert_eq!(date_time.checked_add_days(366), Some(()));
+        assert_eq!(date_time.to_string(), "2024-02-29T12:00:00");
+        assert_eq!(date_time.checked_add_seconds(12 * 60 * 60), Some(()));
+        assert_eq!(date_time.to_string(), "2024-03-01T00:00:00");
+        assert_eq!(date_time.checked_sub_seconds(1), Some(()));
+        assert_eq!(date_time.to_string(), "2024-02-29T23:59:59");
+        assert_eq!(date_time.checked_sub_days(365), Some(()));
+        assert_eq!(date_time.to_string(), "2023-03-01T23:59:59");
+
+        let before = date_time.to_unix_seconds().unwrap();
+        assert_eq!(date_time.checked_add_seconds(1_234_567_890), Some(()));
+        assert_eq!(date_time.to_unix_seconds(), Some(before + 1_234_567_890));
+
+        let mut date_time = DateTime::from_ymd_hms(0, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(date_time.checked_sub_seconds(1), None);
+        assert_eq!(date_time.to_string(), "0000-01-01T00:00:00");
+
+        let mut date_time = DateTime::from_ymd_hms(u64::MAX - 1, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(date_time.checked_add_seconds(1), None);
+        assert_eq!(date_time.checked_add_days(u64::MAX), None);
+        assert_eq!(date_time.increment(), Some(1));
+        assert_eq!(date_time.to_string(), "0000-01-01T00:00:00");
+    }
+}
@@ -0,0 +1,402 @@
+use core::iter::FusedIterator;
+use core::ops::{Add, Div, Rem};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+
+use crate::MixedRadixCounter;
+
+/// An iterator over the states of a [`MixedRadixCounter`] whose elements are strictly
+/// increasing or nondecreasing, in lexicographic order.
+///
+/// With all limits set to `n`, these are the combinations of `E` out of `n` items without or
+/// with repetition.
+///
+/// Created by [`MixedRadixCounter::iter_combinations`] or
+/// [`MixedRadixCounter::iter_multisets`].
+#[derive(Debug, Clone)]
+pub struct Combinations<T, const E: usize> {
+    counter: MixedRadixCounter<T, E>,
+    repeat: bool,
+    finished: bool,
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    /// Returns an iterator over all states whose elements are strictly increasing, starting at
+    /// the smallest one no matter what the current state is.
+    pub fn iter_combinations(&self) -> Combinations<T, E> {
+        self.combinations(false)
+    }
+
+    /// Returns an iterator over all states whose elements are nondecreasing, starting at zero no
+    /// matter what the current state is.
+    pub fn iter_multisets(&self) -> Combinations<T, E> {
+        self.combinations(true)
+    }
+
+    fn combinations(&self, repeat: bool) -> Combinations<T, E> {
+        let mut combinations = Combinations {
+            counter: self.clone(),
+            repeat,
+            finished: false,
+        };
+        // every state has at least the elements of the smallest one, e.g. `[0, 1, 2]`, so if
+        // that doesn't fit there is no state at all
+        combinations.finished = !combinations.fill_from(0, T::default());
+        combinations
+    }
+}
+
+impl<T, const E: usize> Combinations<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    /// Sets the element at `index` to `value` and all following elements to the smallest values
+    /// allowed after it, unless one of them doesn't fit below its limit.
+    fn fill_from(&mut self, index: usize, value: T) -> bool {
+        let limits = &self.counter.limits;
+        let mut elements = self.counter.elements;
+        let mut value = value;
+        for i in index..E {
+            if value >= limits[i] {
+                return false;
+            }
+            elements[i] = value;
+            if !self.repeat {
+                // this can't overflow since `value < limits[i] <= T::MAX`
+                value = value + T::one();
+            }
+        }
+        self.counter.elements = elements;
+        true
+    }
+}
+
+impl<T, const E: usize> Iterator for Combinations<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    type Item = [T; E];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let state = self.counter.elements;
+        // a smaller element at `i` might still fit where a larger one didn't, since its
+        // following elements are smaller as well, so every element has to be tried
+        self.finished = !(0..E).rev().any(|i| self.fill_from(i, state[i] + T::one()));
+        Some(state)
+    }
+}
+
+impl<T, const E: usize> FusedIterator for Combinations<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+}
+
+// Ranks are only defined for counters whose limits are all the same `n`. Nondecreasing
+// elements `c[i]` are ranked as the strictly increasing elements `c[i] + i` below `n + E - 1`,
+// which keeps their order.
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Copy,
+    usize: TryFrom<T>,
+{
+    /// Returns the position of the current state in [`MixedRadixCounter::iter_combinations`],
+    /// or `None` if the elements aren't strictly increasing, the limits aren't all the same, or
+    /// the rank doesn't fit into `W`.
+    pub fn combination_rank<W>(&self) -> Option<W>
+    where
+        W: TryFrom<usize> + Zero + One + CheckedAdd + CheckedSub + CheckedMul,
+        W: Div<Output = W> + Rem<Output = W> + Copy,
+    {
+        let (n, elements) = self.uniform_parts()?;
+        rank(n, &elements)
+    }
+
+    /// Returns the position of the current state in [`MixedRadixCounter::iter_multisets`],
+    /// or `None` if the elements aren't nondecreasing, the limits aren't all the same, or the
+    /// rank doesn't fit into `W`.
+    pub fn multiset_rank<W>(&self) -> Option<W>
+    where
+        W: TryFrom<usize> + Zero + One + CheckedAdd + CheckedSub + CheckedMul,
+        W: Div<Output = W> + Rem<Output = W> + Copy,
+    {
+        let (n, mut elements) = self.uniform_parts()?;
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element += i;
+        }
+        rank((n + E).checked_sub(1)?, &elements)
+    }
+
+    fn uniform_parts(&self) -> Option<(usize, [usize; E])> {
+        let n = match self.limits.first() {
+            Some(&limit) => usize::try_from(limit).ok()?,
+            None => 0,
+        };
+        let mut elements = [0; E];
+        for (i, element) in elements.iter_mut().enumerate() {
+            if usize::try_from(self.limits[i]).ok()? != n {
+                return None;
+            }
+            *element = usize::try_from(self.elements[i]).ok()?;
+        }
+        Some((n, elements))
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+    T: TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+    /// Creates a counter with all limits set to `limit`, whose state is at position `rank` in
+    /// [`MixedRadixCounter::iter_combinations`], or `None` if there is no such state.
+    pub fn from_combination_rank<W>(limit: T, rank: W) -> Option<Self>
+    where
+        W: TryFrom<usize> + Zero + One + CheckedAdd + CheckedSub + CheckedMul,
+        W: Div<Output = W> + Rem<Output = W> + PartialOrd<W> + Copy,
+    {
+        let n = usize::try_from(limit).ok()?;
+        Self::from_uniform_parts(limit, unrank(n, rank)?)
+    }
+
+    /// Creates a counter with all limits set to `limit`, whose state is at position `rank` in
+    /// [`MixedRadixCounter::iter_multisets`], or `None` if there is no such state.
+    pub fn from_multiset_rank<W>(limit: T, rank: W) -> Option<Self>
+    where
+        W: TryFrom<usize> + Zero + One + CheckedAdd + CheckedSub + CheckedMul,
+        W: Div<Output = W> + Rem<Output = W> + PartialOrd<W> + Copy,
+    {
+        let n = usize::try_from(limit).ok()?;
+        let mut elements = unrank::<W, E>((n + E).checked_sub(1)?, rank)?;
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element -= i;
+        }
+        Self::from_uniform_parts(limit, elements)
+    }
+
+    fn from_uniform_parts(limit: T, elements: [usize; E]) -> Option<Self> {
+        let mut converted = [T::default(); E];
+        for (converted, element) in converted.iter_mut().zip(elements) {
+            *converted = T::try_from(element).ok()?;
+        }
+        Self::try_from_limits_and_elements([limit; E], converted).ok()
+    }
+}
+
+fn binomial<W>(n: usize, k: usize) -> Option<W>
+where
+    W: TryFrom<usize> + Zero + One + CheckedMul,
+    W: Div<Output = W> + Rem<Output = W> + Copy,
+{
+    if k > n {
+        return W::try_from(0).ok();
+    }
+    let k = k.min(n - k);
+    let mut result = W::one();
+    for i in 0..k {
+        // `result` is `C(n, i)` here, and `C(n, i) * (n - i)` is divisible by `i + 1`. After
+        // dividing out their common factor, `result` and the rest of `i + 1` are coprime, so
+        // the rest divides `n - i` and the product only overflows if `C(n, i + 1)` does.
+        let divisor = W::try_from(i + 1).ok()?;
+        let common = gcd(result, divisor);
+        let factor = W::try_from(n - i).ok()? / (divisor / common);
+        result = (result / common).checked_mul(&factor)?;
+    }
+    Some(result)
+}
+
+fn gcd<W>(mut a: W, mut b: W) -> W
+where
+    W: Zero + Rem<Output = W> + Copy,
+{
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the lexicographic rank of the strictly increasing `elements` among all strictly
+/// increasing sequences of the same length below `n`.
+fn rank<W, const E: usize>(n: usize, elements: &[usize; E]) -> Option<W>
+where
+    W: TryFrom<usize> + Zero + One + CheckedAdd + CheckedMul,
+    W: Div<Output = W> + Rem<Output = W> + Copy,
+{
+    let mut rank = W::zero();
+    let mut smallest = 0;
+    for (i, &element) in elements.iter().enumerate() {
+        if element < smallest || element >= n {
+            return None;
+        }
+        // count all sequences with the same prefix, but a smaller element at `i`
+        for value in smallest..element {
+            rank = rank.checked_add(&binomial(n - 1 - value, E - 1 - i)?)?;
+        }
+        smallest = element + 1;
+    }
+    Some(rank)
+}
+
+/// The inverse of [`rank`].
+fn unrank<W, const E: usize>(n: usize, rank: W) -> Option<[usize; E]>
+where
+    W: TryFrom<usize> + Zero + One + CheckedSub + CheckedMul,
+    W: Div<Output = W> + Rem<Output = W> + PartialOrd<W> + Copy,
+{
+    let mut elements = [0; E];
+    let mut rest = rank;
+    let mut value = 0;
+    for (i, element) in elements.iter_mut().enumerate() {
+        loop {
+            if value >= n {
+                return None;
+            }
+            // a count that doesn't fit into `W` is larger than any rank
+            match binomial::<W>(n - 1 - value, E - 1 - i) {
+                Some(count) if rest >= count => rest = rest.checked_sub(&count)?,
+                _ => break,
+            }
+            value += 1;
+        }
+        *element = value;
+        value += 1;
+    }
+    if rest.is_zero() {
+        Some(elements)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_combinations() {
+        let mrc = MixedRadixCounter::try_from_limits([5_u8; 3]).unwrap();
+
+        let expected = mrc
+            .iter_states()
+            .filter(|state| state.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(mrc.iter_combinations().eq(expected));
+        assert_eq!(mrc.iter_combinations().count(), 10);
+
+        for (rank, state) in mrc.iter_combinations().enumerate() {
+            let combination =
+                MixedRadixCounter::try_from_limits_and_elements([5; 3], state).unwrap();
+            assert_eq!(combination.combination_rank::<u32>(), Some(rank as u32));
+            assert_eq!(
+                MixedRadixCounter::from_combination_rank(5_u8, rank as u32),
+                Some(combination)
+            );
+        }
+        assert_eq!(
+            MixedRadixCounter::<u8, 3>::from_combination_rank(5, 10_u32),
+            None
+        );
+        assert_eq!(mrc.combination_rank::<u32>(), None);
+    }
+
+    #[test]
+    fn test_multisets() {
+        let mrc = MixedRadixCounter::try_from_limits([3_u8; 3]).unwrap();
+
+        let expected = mrc
+            .iter_states()
+            .filter(|state| state.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(mrc.iter_multisets().eq(expected));
+        assert_eq!(mrc.iter_multisets().count(), 10);
+
+        for (rank, state) in mrc.iter_multisets().enumerate() {
+            let multiset = MixedRadixCounter::try_from_limits_and_elements([3; 3], state).unwrap();
+            assert_eq!(multiset.multiset_rank::<u32>(), Some(rank as u32));
+            assert_eq!(
+                MixedRadixCounter::from_multiset_rank(3_u8, rank as u32),
+                Some(multiset)
+            );
+        }
+        assert_eq!(
+            MixedRadixCounter::<u8, 3>::from_multiset_rank(3, 10_u32),
+            None
+        );
+    }
+
+    #[test]
+    fn test_non_uniform_limits() {
+        let mrc = MixedRadixCounter::try_from_limits([2_u8, 4, 5]).unwrap();
+
+        let expected = mrc
+            .iter_states()
+            .filter(|state| state.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(mrc.iter_combinations().eq(expected));
+        assert_eq!(mrc.combination_rank::<u32>(), None);
+
+        let expected = mrc
+            .iter_states()
+            .filter(|state| state.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(mrc.iter_multisets().eq(expected));
+
+        let mrc = MixedRadixCounter::try_from_limits([3_u8, 2]).unwrap();
+        assert_eq!(mrc.iter_combinations().next(), Some([0, 1]));
+        let mrc = MixedRadixCounter::try_from_limits([3_u8, 1]).unwrap();
+        assert_eq!(mrc.iter_combinations().next(), None);
+    }
+
+    #[test]
+    fn test_large_rank() {
+        let mrc =
+            MixedRadixCounter::try_from_limits_and_elements([100_u8; 4], [96, 97, 98, 99]).unwrap();
+        assert_eq!(mrc.combination_rank::<u64>(), Some(3_921_225 - 1));
+        assert_eq!(mrc.combination_rank::<u16>(), None);
+        assert_eq!(
+            MixedRadixCounter::from_combination_rank(100_u8, 3_921_224_u64),
+            Some(mrc)
+        );
+    }
+
+    #[test]
+    fn test_narrow_rank() {
+        let mrc = MixedRadixCounter::try_from_limits([20_u8; 3]).unwrap();
+        for (rank, state) in mrc.iter_combinations().enumerate() {
+            let combination =
+                MixedRadixCounter::try_from_limits_and_elements([20; 3], state).unwrap();
+            let expected = u8::try_from(rank).ok();
+            assert_eq!(combination.combination_rank::<u64>(), Some(rank as u64));
+            assert_eq!(combination.combination_rank::<u8>(), expected);
+            if let Some(rank) = expected {
+                assert_eq!(
+                    MixedRadixCounter::from_combination_rank(20_u8, rank),
+                    Some(combination)
+                );
+            }
+        }
+
+        let combination =
+            MixedRadixCounter::try_from_limits_and_elements([20_u8; 3], [1, 2, 3]).unwrap();
+        assert_eq!(combination.combination_rank::<u8>(), Some(171));
+        // the number of combinations doesn't fit into `u8`, but the first ones still have ranks
+        let first = MixedRadixCounter::try_from_limits_and_elements([30_u8; 3], [0, 1, 2]).unwrap();
+        assert_eq!(
+            MixedRadixCounter::from_combination_rank(30_u8, 0_u8),
+            Some(first)
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use core::iter::FusedIterator;
+use core::ops::{Add, Deref, Div, Rem, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::{digits, InvalidValues};
+
+/// The limits of a [`DependentCounter`], where the limit of every element may depend on the
+/// more significant elements.
+///
+/// This is implemented for arrays of fixed limits and for closures that take the index of an
+/// element together with all more significant elements.
+pub trait Limits<T> {
+    /// Returns the limit of the element at `index`, given the elements before it in `prefix`.
+    ///
+    /// The limit has to be positive for every prefix that can occur, since otherwise the
+    /// counter would have no valid state with that prefix.
+    fn limit(&self, index: usize, prefix: &[T]) -> T;
+
+    /// Returns the limits of all elements if they don't depend on the prefix, which lets
+    /// [`DependentCounter::add`] and [`DependentCounter::sub`] skip many states at once.
+    fn fixed(&self) -> Option<&[T]> {
+        None
+    }
+}
+
+impl<T, const E: usize> Limits<T> for [T; E]
+where
+    T: Copy,
+{
+    fn limit(&self, index: usize, _prefix: &[T]) -> T {
+        self[index]
+    }
+
+    fn fixed(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
+
+impl<T, F> Limits<T> for F
+where
+    F: Fn(usize, &[T]) -> T,
+{
+    fn limit(&self, index: usize, prefix: &[T]) -> T {
+        self(index, prefix)
+    }
+}
+
+/// A counter like [`MixedRadixCounter`](crate::MixedRadixCounter), whose limits are given by a
+/// [`Limits`] implementation instead of being fixed.
+///
+/// This can count through e.g. the days of the year with a limit for the day that depends on
+/// the month, or through restricted growth strings.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DependentCounter<T, L, const E: usize> {
+    elements: [T; E],
+    limits: L,
+}
+
+impl<T, L, const E: usize> Deref for DependentCounter<T, L, E> {
+    type Target = [T; E];
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<T, L, const E: usize> DependentCounter<T, L, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+    L: Limits<T>,
+{
+    pub fn try_from_limits(limits: L) -> Result<Self, InvalidValues<T>> {
+        Self::try_from_limits_and_elements(limits, [T::default(); E])
+    }
+
+    /// Fails if any element isn't smaller than the limit that results from the elements before
+    /// it.
+    pub fn try_from_limits_and_elements(
+        limits: L,
+        elements: [T; E],
+    ) -> Result<Self, InvalidValues<T>> {
+        for (i, &element) in elements.iter().enumerate() {
+            digits::validate_element(i, element, limits.limit(i, &elements[..i]))?;
+        }
+        Ok(Self { elements, limits })
+    }
+
+    /// Returns the limit of the element at `index` for the current state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn limit(&self, index: usize) -> T {
+        self.limits.limit(index, &self.elements[..index])
+    }
+
+    pub fn limits(&self) -> &L {
+        &self.limits
+    }
+
+    /// Returns the limits and the elements.
+    pub fn into_parts(self) -> (L, [T; E]) {
+        (self.limits, self.elements)
+    }
+}
+
+impl<T, L, const E: usize> DependentCounter<T, L, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    L: Limits<T>,
+{
+    /// Returns an iterator over all states from the current one up to and including the
+    /// largest state, without modifying this counter.
+    pub fn iter_states(&self) -> DependentStates<T, L, E>
+    where
+        L: Clone,
+    {
+        DependentStates {
+            counter: self.clone(),
+            finished: false,
+        }
+    }
+
+    pub fn increment(&mut self) -> Option<T> {
+        for i in (0..E).rev() {
+            // this can't overflow since `elements[i] < limit <= T::MAX`
+            let sum = self.elements[i] + T::one();
+            if sum < self.limit(i) {
+                self.elements[i] = sum;
+                return None;
+            }
+            // zero is valid for every prefix, so the less significant elements stay valid
+            self.elements[i] = T::default();
+        }
+        Some(T::one())
+    }
+
+    pub fn decrement(&mut self) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T>,
+    {
+        let changed = self.elements.iter().rposition(|element| !element.is_zero());
+        if let Some(i) = changed {
+            self.elements[i] = self.elements[i] - T::one();
+        }
+        // the limits of all less significant elements may have changed, so they are set to
+        // their largest values from the most significant one down
+        let first = changed.map_or(0, |i| i + 1);
+        for i in first..E {
+            self.elements[i] = self.limit(i) - T::one();
+        }
+        match changed {
+            Some(_) => None,
+            None => Some(T::one()),
+        }
+    }
+
+    /// Moves `value` states forward, and returns how often the counter wrapped around.
+    ///
+    /// If [`Limits::fixed`] returns the limits, this is as fast as [`MixedRadixCounter::add`].
+    /// Otherwise the limits of the less significant elements can change with every state of
+    /// the more significant ones, so this steps through all states of the more significant
+    /// elements that it passes, skipping only along the least significant element. That takes
+    /// time proportional to `value` divided by the limit of the least significant element.
+    ///
+    /// [`MixedRadixCounter::add`]: crate::MixedRadixCounter::add
+    pub fn add(&mut self, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        if let Some(limits) = self.limits.fixed() {
+            return digits::add(&mut self.elements, &limits[..E], value);
+        }
+
+        let mut rest = value;
+        let mut carry = T::zero();
+        while !rest.is_zero() {
+            let Some(last) = E.checked_sub(1) else {
+                // the only state is the empty one, so every step wraps around
+                return Some(rest);
+            };
+            let headroom = self.limit(last) - T::one() - self.elements[last];
+            if rest <= headroom {
+                self.elements[last] = self.elements[last] + rest;
+                break;
+            }
+            // go to the largest value of the last element and then one more step, which
+            // changes the more significant elements
+            rest = rest - headroom - T::one();
+            self.elements[last] = self.elements[last] + headroom;
+            if self.increment().is_some() {
+                // this can't overflow, since every wrap around takes at least one step
+                carry = carry + T::one();
+            }
+        }
+
+        if carry.is_zero() {
+            None
+        } else {
+            Some(carry)
+        }
+    }
+
+    /// Moves `value` states backward, and returns how often the counter wrapped around.
+    ///
+    /// See [`DependentCounter::add`] for how long this takes.
+    pub fn sub(&mut self, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        if let Some(limits) = self.limits.fixed() {
+            return digits::sub(&mut self.elements, &limits[..E], value);
+        }
+
+        let mut rest = value;
+        let mut borrow = T::zero();
+        while !rest.is_zero() {
+            let Some(last) = E.checked_sub(1) else {
+                return Some(rest);
+            };
+            let element = self.elements[last];
+            if rest <= element {
+                self.elements[last] = element - rest;
+                break;
+            }
+            rest = rest - element - T::one();
+            self.elements[last] = T::zero();
+            if self.decrement().is_some() {
+                borrow = borrow + T::one();
+            }
+        }
+
+        if borrow.is_zero() {
+            None
+        } else {
+            Some(borrow)
+        }
+    }
+}
+
+/// An iterator over the states of a [`DependentCounter`], from its current state up to and
+/// including its largest state.
+///
+/// Created by [`DependentCounter::iter_states`] or by [`IntoIterator::into_iter`].
+#[derive(Debug, Clone)]
+pub struct DependentStates<T, L, const E: usize> {
+    counter: DependentCounter<T, L, E>,
+    finished: bool,
+}
+
+impl<T, L, const E: usize> IntoIterator for DependentCounter<T, L, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    L: Limits<T>,
+{
+    type Item = [T; E];
+    type IntoIter = DependentStates<T, L, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DependentStates {
+            counter: self,
+            finished: false,
+        }
+    }
+}
+
+impl<T, L, const E: usize> Iterator for DependentStates<T, L, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    L: Limits<T>,
+{
+    type Item = [T; E];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let state = self.counter.elements;
+        self.finished = self.counter.increment().is_some();
+        Some(state)
+    }
+}
+
+impl<T, L, const E: usize> FusedIterator for DependentStates<T, L, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    L: Limits<T>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DependentCounter, InvalidValues, MixedRadixCounter};
+
+    const DAYS: [u16; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+    fn month_lengths(index: usize, prefix: &[u16]) -> u16 {
+        match index {
+            0 => 12,
+            _ => DAYS[prefix[0] as usize],
+        }
+    }
+
+    /// Restricted growth strings, which enumerate the partitions of a set.
+    fn restricted_growth(_index: usize, prefix: &[u8]) -> u8 {
+        prefix.iter().max().map_or(1, |max| max + 2)
+    }
+
+    #[test]
+    fn test_days_of_year() {
+        let mut counter = DependentCounter::try_from_limits(month_lengths).unwrap();
+        assert_eq!(counter.iter_states().count(), 365);
+
+        assert_eq!(counter.add(58), None);
+        assert_eq!(*counter, [1, 27]);
+        assert_eq!(counter.limit(1), 28);
+        assert_eq!(counter.increment(), None);
+        assert_eq!(*counter, [2, 0]);
+        assert_eq!(counter.decrement(), None);
+        assert_eq!(*counter, [1, 27]);
+
+        assert_eq!(counter.add(365 + 306), Some(1));
+        assert_eq!(*counter, [11, 30]);
+        assert_eq!(counter.sub(365 + 364), Some(1));
+        assert_eq!(*counter, [0, 0]);
+        assert_eq!(counter.sub(1), Some(1));
+        assert_eq!(*counter, [11, 30]);
+    }
+
+    #[test]
+    fn test_set_partitions() {
+        let counter = DependentCounter::try_from_limits(restricted_growth).unwrap();
+        let states: [[u8; 4]; 15] = core::array::from_fn({
+            let mut states = counter.into_iter();
+            move |_| states.next().unwrap()
+        });
+        assert_eq!(
+            states[..4],
+            [[0, 0, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0], [0, 0, 1, 1]]
+        );
+        assert_eq!(states[14], [0, 1, 2, 3]);
+        assert_eq!(counter.into_iter().count(), 15);
+
+        assert_eq!(
+            DependentCounter::try_from_limits_and_elements(restricted_growth, [0, 1, 3, 0]).err(),
+            Some(InvalidValues::OutOfRange {
+                index: 2,
+                element: 3,
+                limit: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_matches_mixed_radix_counter() {
+        let limits = [3_u8, 1, 4, 5];
+        let mut counter = DependentCounter::try_from_limits(limits).unwrap();
+        // the same limits, but without `Limits::fixed`, so that they are evaluated per state
+        let mut dependent = DependentCounter::try_from_limits(|i, _: &[u8]| limits[i]).unwrap();
+        let mut mrc = MixedRadixCounter::try_from_limits(limits).unwrap();
+        for value in 0..150 {
+            let carry = mrc.add(value);
+            assert_eq!(counter.add(value), carry);
+            assert_eq!(dependent.add(value), carry);
+            assert_eq!((*counter, *dependent), (*mrc, *mrc));
+
+            let borrow = mrc.sub(value / 3);
+            assert_eq!(counter.sub(value / 3), borrow);
+            assert_eq!(dependent.sub(value / 3), borrow);
+            assert_eq!((*counter, *dependent), (*mrc, *mrc));
+        }
+        assert!(counter.iter_states().eq(mrc.iter_states()));
+        assert!(dependent.iter_states().eq(mrc.iter_states()));
+
+        // this would take about 2^63 steps without `Limits::fixed`
+        let mut counter = DependentCounter::try_from_limits([u64::MAX, 2]).unwrap();
+        assert_eq!(counter.add(u64::MAX), None);
+        assert_eq!(*counter, [u64::MAX / 2, 1]);
+        assert_eq!(counter.sub(u64::MAX), None);
+        assert_eq!(*counter, [0, 0]);
+
+        let mut counter = DependentCounter::<u8, _, 0>::try_from_limits([]).unwrap();
+        assert_eq!(counter.add(3), Some(3));
+        assert_eq!(counter.increment(), Some(1));
+    }
+}
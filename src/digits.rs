@@ -0,0 +1,291 @@
+use core::ops::{Add, Div, Rem, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::InvalidValues;
+
+// The algorithms in here work on slices, so that they can be shared between counters that
+// store their elements differently. Callers make sure that `elements` and `limits` have the
+// same length and that every element is smaller than its limit.
+
+pub(crate) fn increment<T>(elements: &mut [T], limits: &[T]) -> Option<T>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    for i in (0..elements.len()).rev() {
+        // this can't overflow since `elements[i] < limit <= T::MAX`
+        let sum = elements[i].add(T::one());
+        if sum < limits[i] {
+            elements[i] = sum;
+            return None;
+        }
+        elements[i] = T::default();
+    }
+    Some(T::one())
+}
+
+pub(crate) fn advance_at<T>(elements: &mut [T], limits: &[T], index: usize) -> Option<T>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    elements[index + 1..].fill(T::default());
+    increment(&mut elements[..=index], &limits[..=index])
+}
+
+pub(crate) fn decrement<T>(elements: &mut [T], limits: &[T]) -> Option<T>
+where
+    T: One + Zero,
+    T: Sub<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    for i in (0..elements.len()).rev() {
+        if elements[i] > T::zero() {
+            elements[i] = elements[i] - T::one();
+            return None;
+        }
+        elements[i] = limits[i] - T::one();
+    }
+    Some(T::one())
+}
+
+pub(crate) fn add<T>(elements: &mut [T], limits: &[T], value: T) -> Option<T>
+where
+    T: One + Zero,
+    T: Add<Output = T> + Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut carry = value;
+    for i in (0..elements.len()).rev() {
+        if carry == T::zero() {
+            return None;
+        }
+
+        // split the carry before touching the element, so that no intermediate value can
+        // exceed `limit`, which itself is at most `T::MAX`
+        let limit = limits[i];
+        let remainder = carry % limit;
+        carry = carry / limit;
+
+        // `elements[i] + remainder` could overflow, so compare against the headroom instead
+        let headroom = limit - elements[i];
+        if remainder < headroom {
+            elements[i] = elements[i] + remainder;
+        } else {
+            elements[i] = remainder - headroom;
+            // this can't overflow, since `remainder > 0` implies `limit > 1`,
+            // which means that `carry <= T::MAX / 2`
+            carry = carry + T::one();
+        }
+    }
+
+    if carry == T::zero() {
+        None
+    } else {
+        Some(carry)
+    }
+}
+
+pub(crate) fn sub<T>(elements: &mut [T], limits: &[T], value: T) -> Option<T>
+where
+    T: One + Zero,
+    T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut borrow = value;
+    for i in (0..elements.len()).rev() {
+        if borrow == T::zero() {
+            return None;
+        }
+
+        // same as in `add`, split the borrow first, so that `remainder < limit`
+        let limit = limits[i];
+        let remainder = borrow % limit;
+        borrow = borrow / limit;
+
+        if remainder <= elements[i] {
+            elements[i] = elements[i] - remainder;
+        } else {
+            elements[i] = limit - (remainder - elements[i]);
+            // this can't overflow for the same reason as in `add`
+            borrow = borrow + T::one();
+        }
+    }
+
+    if borrow == T::zero() {
+        None
+    } else {
+        Some(borrow)
+    }
+}
+
+pub(crate) fn validate<T>(limits: &[T], elements: &[T]) -> Result<(), InvalidValues<T>>
+where
+    T: Default + PartialOrd<T> + Copy,
+{
+    if limits.len() != elements.len() {
+        return Err(InvalidValues::LengthMismatch {
+            limits: limits.len(),
+            elements: elements.len(),
+        });
+    }
+    for (index, (&element, &limit)) in elements.iter().zip(limits.iter()).enumerate() {
+        validate_element(index, element, limit)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_element<T>(
+    index: usize,
+    element: T,
+    limit: T,
+) -> Result<(), InvalidValues<T>>
+where
+    T: Default + PartialOrd<T>,
+{
+    // the default is the smallest element, so no element can be smaller than such a limit
+    if limit <= T::default() {
+        return Err(InvalidValues::ZeroLimit { index });
+    }
+    if element >= limit {
+        return Err(InvalidValues::OutOfRange {
+            index,
+            element,
+            limit,
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn add_elements<T>(elements: &mut [T], other: &[T], limits: &[T]) -> Option<T>
+where
+    T: One,
+    T: Add<Output = T> + Sub<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut carry = false;
+    for i in (0..elements.len()).rev() {
+        // this can't overflow since `other[i] < limit <= T::MAX`
+        let addend = if carry { other[i] + T::one() } else { other[i] };
+
+        // same as in `add`, compare against the headroom so that nothing can overflow
+        let headroom = limits[i] - elements[i];
+        carry = addend >= headroom;
+        if carry {
+            elements[i] = addend - headroom;
+        } else {
+            elements[i] = elements[i] + addend;
+        }
+    }
+    carry.then(T::one)
+}
+
+pub(crate) fn sub_elements<T>(elements: &mut [T], other: &[T], limits: &[T]) -> Option<T>
+where
+    T: One,
+    T: Add<Output = T> + Sub<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut borrow = false;
+    for i in (0..elements.len()).rev() {
+        // this can't overflow since `other[i] < limit <= T::MAX`
+        let subtrahend = if borrow {
+            other[i] + T::one()
+        } else {
+            other[i]
+        };
+
+        borrow = subtrahend > elements[i];
+        if borrow {
+            elements[i] = limits[i] - (subtrahend - elements[i]);
+        } else {
+            elements[i] = elements[i] - subtrahend;
+        }
+    }
+    borrow.then(T::one)
+}
+
+pub(crate) fn mul<T>(elements: &mut [T], limits: &[T], factor: T) -> Option<T>
+where
+    T: One + Zero,
+    T: Add<Output = T> + Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut carry = T::zero();
+    for i in (0..elements.len()).rev() {
+        // the carry is always smaller than `factor`, so is the quotient
+        let (quotient, remainder) = mul_add_div_rem(elements[i], factor, carry, limits[i]);
+        elements[i] = remainder;
+        carry = quotient;
+    }
+
+    if carry == T::zero() {
+        None
+    } else {
+        Some(carry)
+    }
+}
+
+/// Divides the elements by `divisor` in place and returns the remainder.
+pub(crate) fn div_rem<T>(elements: &mut [T], limits: &[T], divisor: T) -> T
+where
+    T: One + Zero,
+    T: Add<Output = T> + Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let mut remainder = T::zero();
+    for i in 0..elements.len() {
+        // `remainder < divisor`, so the quotient is smaller than `limits[i]`
+        let (quotient, new_remainder) = mul_add_div_rem(remainder, limits[i], elements[i], divisor);
+        elements[i] = quotient;
+        remainder = new_remainder;
+    }
+    remainder
+}
+
+/// Returns the quotient and remainder of `(a * b + c) / divisor` without ever computing
+/// `a * b`, as long as `a < divisor` and the quotient fits into `T`.
+///
+/// This works like binary long multiplication, where every intermediate value is kept as
+/// a `(quotient, remainder)` pair with respect to `divisor`.
+fn mul_add_div_rem<T>(a: T, b: T, c: T, divisor: T) -> (T, T)
+where
+    T: One + Zero,
+    T: Add<Output = T> + Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let two = T::one() + T::one();
+
+    let mut result = (c / divisor, c % divisor);
+    // `base` is `a * 2^i` for the i-th bit of `b`, so its quotient is smaller than `2^i <= b`
+    let mut base = (T::zero(), a);
+    let mut factor = b;
+    while factor > T::zero() {
+        if factor % two == T::one() {
+            result = add_pairs(result, base, divisor);
+        }
+        factor = factor / two;
+        if factor > T::zero() {
+            base = add_pairs(base, base, divisor);
+        }
+    }
+    result
+}
+
+fn add_pairs<T>(lhs: (T, T), rhs: (T, T), divisor: T) -> (T, T)
+where
+    T: One,
+    T: Add<Output = T> + Sub<T, Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    // both remainders are smaller than `divisor`, so compare against the headroom as in `add`
+    let headroom = divisor - lhs.1;
+    if rhs.1 < headroom {
+        (lhs.0 + rhs.0, lhs.1 + rhs.1)
+    } else {
+        (lhs.0 + rhs.0 + T::one(), rhs.1 - headroom)
+    }
+}
@@ -0,0 +1,181 @@
+use core::time::Duration;
+
+use crate::{DurationError, MixedRadixCounter};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Limits for common ways to split a [`Duration`], together with the duration of one step of
+/// the least significant element.
+///
+/// The most significant element is limited by [`u64::MAX`] only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationUnits<const E: usize> {
+    pub limits: [u64; E],
+    pub resolution: Duration,
+}
+
+impl DurationUnits<2> {
+    pub const SECONDS_NANOS: Self = Self {
+        limits: [u64::MAX, 1_000_000_000],
+        resolution: Duration::from_nanos(1),
+    };
+}
+
+impl DurationUnits<3> {
+    pub const HOURS_MINUTES_SECONDS: Self = Self {
+        limits: [u64::MAX, 60, 60],
+        resolution: Duration::from_secs(1),
+    };
+
+    pub const MINUTES_SECONDS_MILLIS: Self = Self {
+        limits: [u64::MAX, 60, 1000],
+        resolution: Duration::from_millis(1),
+    };
+}
+
+impl DurationUnits<4> {
+    pub const DAYS_HOURS_MINUTES_SECONDS: Self = Self {
+        limits: [u64::MAX, 24, 60, 60],
+        resolution: Duration::from_secs(1),
+    };
+}
+
+impl DurationUnits<5> {
+    pub const DAYS_HOURS_MINUTES_SECONDS_MILLIS: Self = Self {
+        limits: [u64::MAX, 24, 60, 60, 1000],
+        resolution: Duration::from_millis(1),
+    };
+
+    /// Years of 365 days, like in the example of the README.
+    pub const YEARS_DAYS_HOURS_MINUTES_SECONDS: Self = Self {
+        limits: [u64::MAX, 365, 24, 60, 60],
+        resolution: Duration::from_secs(1),
+    };
+}
+
+impl<const E: usize> DurationUnits<E> {
+    /// See [`MixedRadixCounter::from_duration`].
+    pub fn counter(
+        &self,
+        duration: Duration,
+    ) -> Result<(MixedRadixCounter<u64, E>, Duration), DurationError<u64>> {
+        MixedRadixCounter::from_duration(self.limits, self.resolution, duration)
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+    T: TryFrom<u128>,
+    u128: TryFrom<T>,
+{
+    /// Creates a counter with the given limits, whose state is the number of whole
+    /// `resolution`s in `duration`.
+    ///
+    /// The part of `duration` that is smaller than `resolution` is returned alongside the
+    /// counter. Fails if the number of steps doesn't fit into the counter, instead of wrapping
+    /// around like [`MixedRadixCounter::from_scalar`].
+    pub fn from_duration(
+        limits: [T; E],
+        resolution: Duration,
+        duration: Duration,
+    ) -> Result<(Self, Duration), DurationError<T>> {
+        let resolution = resolution.as_nanos();
+        if resolution == 0 {
+            return Err(DurationError::ZeroResolution);
+        }
+
+        let nanos = duration.as_nanos();
+        let (counter, excess) = Self::from_scalar(limits, nanos / resolution)?;
+        if excess != 0 {
+            return Err(DurationError::Overflow);
+        }
+
+        // the remainder is smaller than the resolution, which is a `Duration` itself
+        let remainder = nanos % resolution;
+        let Ok(seconds) = u64::try_from(remainder / NANOS_PER_SECOND) else {
+            unreachable!("the remainder is smaller than a Duration");
+        };
+        let remainder = Duration::new(seconds, (remainder % NANOS_PER_SECOND) as u32);
+        Ok((counter, remainder))
+    }
+
+    /// Returns the duration of as many `resolution`s as the current state represents, or `None`
+    /// if that doesn't fit into a [`Duration`].
+    pub fn to_duration(&self, resolution: Duration) -> Option<Duration> {
+        let nanos = self
+            .to_scalar::<u128>()?
+            .checked_mul(resolution.as_nanos())?;
+        let seconds = u64::try_from(nanos / NANOS_PER_SECOND).ok()?;
+        Some(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{DurationError, DurationUnits, InvalidValues, MixedRadixCounter};
+
+    #[test]
+    fn test_round_trip() {
+        let units = DurationUnits::YEARS_DAYS_HOURS_MINUTES_SECONDS;
+        let duration = Duration::from_secs(69_413_798);
+        let (mrc, remainder) = units.counter(duration).unwrap();
+        assert_eq!(*mrc, [2, 73, 9, 36, 38]);
+        assert_eq!(remainder, Duration::ZERO);
+        assert_eq!(mrc.to_duration(units.resolution), Some(duration));
+
+        let units = DurationUnits::SECONDS_NANOS;
+        let duration = Duration::new(u64::MAX - 1, 999_999_999);
+        let (mrc, remainder) = units.counter(duration).unwrap();
+        assert_eq!(*mrc, [u64::MAX - 1, 999_999_999]);
+        assert_eq!(remainder, Duration::ZERO);
+        assert_eq!(mrc.to_duration(units.resolution), Some(duration));
+
+        // the most significant element can't reach its limit
+        assert_eq!(units.counter(Duration::MAX), Err(DurationError::Overflow));
+    }
+
+    #[test]
+    fn test_remainder() {
+        let units = DurationUnits::DAYS_HOURS_MINUTES_SECONDS;
+        let (mrc, remainder) = units.counter(Duration::new(90_061, 500)).unwrap();
+        assert_eq!(*mrc, [1, 1, 1, 1]);
+        assert_eq!(remainder, Duration::from_nanos(500));
+
+        let (mrc, remainder) = MixedRadixCounter::from_duration(
+            [10_u8, 10],
+            Duration::from_secs(u64::MAX / 2),
+            Duration::MAX,
+        )
+        .unwrap();
+        assert_eq!(*mrc, [0, 2]);
+        assert_eq!(remainder, Duration::new(1, 999_999_999));
+    }
+
+    #[test]
+    fn test_errors() {
+        let resolution = Duration::from_millis(10);
+        assert_eq!(
+            MixedRadixCounter::from_duration([60_u8, 100], resolution, Duration::from_secs(3600)),
+            Err(DurationError::Overflow)
+        );
+        assert_eq!(
+            MixedRadixCounter::from_duration([60_u8, 0], resolution, Duration::ZERO),
+            Err(DurationError::InvalidValues(InvalidValues::ZeroLimit {
+                index: 1
+            }))
+        );
+        assert_eq!(
+            MixedRadixCounter::from_duration([60_u8], Duration::ZERO, Duration::ZERO),
+            Err(DurationError::ZeroResolution)
+        );
+
+        let mrc =
+            MixedRadixCounter::try_from_limits_and_elements([u64::MAX, 60], [u64::MAX - 1, 59])
+                .unwrap();
+        assert_eq!(mrc.to_duration(Duration::from_secs(1)), None);
+        assert_eq!(mrc.to_duration(Duration::ZERO), Some(Duration::ZERO));
+    }
+}
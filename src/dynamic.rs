@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Deref, Div, Rem, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::{digits, InvalidValues, MixedRadixCounter};
+
+/// A [`MixedRadixCounter`] whose number of elements is only known at runtime.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DynMixedRadixCounter<T> {
+    elements: Vec<T>,
+    limits: Vec<T>,
+}
+
+impl<T> Deref for DynMixedRadixCounter<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<T> DynMixedRadixCounter<T>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    pub fn increment(&mut self) -> Option<T> {
+        digits::increment(&mut self.elements, &self.limits)
+    }
+
+    pub fn decrement(&mut self) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T>,
+    {
+        digits::decrement(&mut self.elements, &self.limits)
+    }
+
+    pub fn add(&mut self, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        digits::add(&mut self.elements, &self.limits, value)
+    }
+
+    pub fn sub(&mut self, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        digits::sub(&mut self.elements, &self.limits, value)
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for DynMixedRadixCounter<T>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    type Error = InvalidValues<T>;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_limits(value)
+    }
+}
+
+impl<T> DynMixedRadixCounter<T>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    pub fn try_from_limits(limits: Vec<T>) -> Result<Self, InvalidValues<T>> {
+        let elements = alloc::vec![T::default(); limits.len()];
+        Self::try_from_limits_and_elements(limits, elements)
+    }
+
+    /// Fails if any element isn't smaller than its limit, or if `limits` and `elements` don't
+    /// have the same length.
+    pub fn try_from_limits_and_elements(
+        limits: Vec<T>,
+        elements: Vec<T>,
+    ) -> Result<Self, InvalidValues<T>> {
+        digits::validate(&limits, &elements)?;
+        Ok(Self { elements, limits })
+    }
+}
+
+impl<T, const E: usize> From<MixedRadixCounter<T, E>> for DynMixedRadixCounter<T> {
+    fn from(value: MixedRadixCounter<T, E>) -> Self {
+        Self {
+            elements: value.elements.into(),
+            limits: value.limits.into(),
+        }
+    }
+}
+
+/// Fails if the number of elements is not `E`, in which case the counter is returned unchanged.
+impl<T, const E: usize> TryFrom<DynMixedRadixCounter<T>> for MixedRadixCounter<T, E> {
+    type Error = DynMixedRadixCounter<T>;
+
+    fn try_from(value: DynMixedRadixCounter<T>) -> Result<Self, Self::Error> {
+        if value.elements.len() != E {
+            return Err(value);
+        }
+
+        let DynMixedRadixCounter { elements, limits } = value;
+        let (Ok(elements), Ok(limits)) = (elements.try_into(), limits.try_into()) else {
+            unreachable!("elements and limits always have the same length");
+        };
+        Ok(Self { elements, limits })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{DynMixedRadixCounter, InvalidValues, MixedRadixCounter};
+
+    #[test]
+    fn test_increment() {
+        let mut mrc = DynMixedRadixCounter::try_from_limits(vec![2_u8, 4, 3]).unwrap();
+        let mut expected = MixedRadixCounter::try_from_limits([2_u8, 4, 3]).unwrap();
+
+        for _ in 0..23 {
+            assert_eq!(mrc.increment(), expected.increment());
+            assert_eq!(*mrc, *expected);
+        }
+        assert_eq!(mrc.increment(), Some(1));
+        assert_eq!(*mrc, [0, 0, 0]);
+
+        assert_eq!(mrc.decrement(), Some(1));
+        assert_eq!(*mrc, [1, 3, 2]);
+        assert_eq!(mrc.decrement(), None);
+        assert_eq!(*mrc, [1, 3, 1]);
+    }
+
+    #[test]
+    fn test_large_add() {
+        let mut mrc =
+            DynMixedRadixCounter::try_from_limits(vec![u64::MAX, 365, 24, 60, 60, 1000]).unwrap();
+
+        assert_eq!(mrc.add(69_413_798), None);
+        assert_eq!(*mrc, [0, 0, 19, 16, 53, 798]);
+
+        assert_eq!(mrc.sub(69_413_799), Some(1));
+        assert_eq!(*mrc, [u64::MAX - 1, 364, 23, 59, 59, 999]);
+    }
+
+    #[test]
+    fn test_try_from_limits_and_elements() {
+        assert!(
+            DynMixedRadixCounter::try_from_limits_and_elements(vec![2_u8, 3], vec![1, 2]).is_ok()
+        );
+        assert!(
+            DynMixedRadixCounter::try_from_limits_and_elements(vec![2_u8, 3], vec![1, 3]).is_err()
+        );
+        assert_eq!(
+            DynMixedRadixCounter::try_from_limits_and_elements(vec![2_u8, 3], vec![1]),
+            Err(InvalidValues::LengthMismatch {
+                limits: 2,
+                elements: 1
+            })
+        );
+        assert!(DynMixedRadixCounter::try_from_limits(vec![2_u8, 0]).is_err());
+        assert!(DynMixedRadixCounter::<u8>::try_from_limits(vec![]).is_ok());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([2_u8, 4, 3], [1, 2, 0]).unwrap();
+
+        let dynamic = DynMixedRadixCounter::from(mrc.clone());
+        assert_eq!(*dynamic, [1, 2, 0]);
+
+        assert_eq!(MixedRadixCounter::try_from(dynamic.clone()), Ok(mrc));
+        assert_eq!(
+            MixedRadixCounter::<u8, 2>::try_from(dynamic.clone()),
+            Err(dynamic)
+        );
+    }
+}
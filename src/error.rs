@@ -0,0 +1,85 @@
+use core::fmt::{Display, Formatter};
+
+/// The reason why elements don't fit their limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidValues<T> {
+    /// The limit at `index` is zero, so no element can be smaller than it.
+    ZeroLimit { index: usize },
+    /// The element at `index` is not smaller than its limit.
+    OutOfRange { index: usize, element: T, limit: T },
+    /// There is not exactly one limit per element.
+    LengthMismatch { limits: usize, elements: usize },
+    /// There is no element at `index`, since there are only `len` of them.
+    IndexOutOfBounds { index: usize, len: usize },
+}
+
+impl<T> Display for InvalidValues<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InvalidValues::ZeroLimit { index } => write!(f, "limit {index} is zero"),
+            InvalidValues::OutOfRange {
+                index,
+                element,
+                limit,
+            } => write!(
+                f,
+                "element {index} is {element}, which is not smaller than its limit {limit}"
+            ),
+            InvalidValues::LengthMismatch { limits, elements } => {
+                write!(f, "got {limits} limits for {elements} elements")
+            }
+            InvalidValues::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for {len} elements")
+            }
+        }
+    }
+}
+
+impl<T> core::error::Error for InvalidValues<T> where T: core::fmt::Debug + Display {}
+
+/// Two counters that were combined don't have the same limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitsMismatch;
+
+impl Display for LimitsMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the counters have different limits")
+    }
+}
+
+impl core::error::Error for LimitsMismatch {}
+
+/// The reason why a [`Duration`](core::time::Duration) can't be converted into a counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationError<T> {
+    /// The limits are invalid.
+    InvalidValues(InvalidValues<T>),
+    /// The resolution is zero, so no number of steps adds up to the duration.
+    ZeroResolution,
+    /// The duration has more steps than the counter has states.
+    Overflow,
+}
+
+impl<T> From<InvalidValues<T>> for DurationError<T> {
+    fn from(value: InvalidValues<T>) -> Self {
+        DurationError::InvalidValues(value)
+    }
+}
+
+impl<T> Display for DurationError<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DurationError::InvalidValues(e) => e.fmt(f),
+            DurationError::ZeroResolution => f.write_str("the resolution is zero"),
+            DurationError::Overflow => f.write_str("the duration doesn't fit into the counter"),
+        }
+    }
+}
+
+impl<T> core::error::Error for DurationError<T> where T: core::fmt::Debug + Display {}
@@ -0,0 +1,439 @@
+use core::fmt::{Display, Formatter, Write};
+use core::ops::Sub;
+use core::str::FromStr;
+
+use num_traits::One;
+
+use crate::{digits, MixedRadixCounter};
+
+/// Describes how the state of a [`MixedRadixCounter`] is rendered as and parsed from text.
+///
+/// By default, elements are separated by `:` and all elements except the most significant one
+/// are zero-padded to the number of characters that their largest value (`limit - 1`) needs,
+/// so `[2, 73, 9, 36, 38]` with limits `[u64::MAX, 365, 24, 60, 60]` renders as `2:073:09:36:38`.
+/// This is also what the [`Display`] implementation of [`MixedRadixCounter`] uses.
+///
+/// ```
+/// # use mixed_radix_counter::{Format, MixedRadixCounter};
+/// let mrc = MixedRadixCounter::try_from_limits_and_elements(
+///     [u64::MAX, 365, 24, 60, 60],
+///     [2, 73, 9, 36, 38],
+/// )
+/// .unwrap();
+///
+/// let format = Format::new()
+///     .separator(" ")
+///     .units(["y", "d", "h", "m", "s"])
+///     .zero_pad_elements([false, false, true, true, true]);
+/// assert_eq!(format.display(&mrc).to_string(), "2y 73d 09h 36m 38s");
+/// assert_eq!(format.parse("2y 73d 09h 36m 38s", [u64::MAX, 365, 24, 60, 60]), Ok(mrc));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Format<'a, const E: usize> {
+    separator: &'a str,
+    units: Option<[&'a str; E]>,
+    zero_pad: [bool; E],
+}
+
+impl<const E: usize> Default for Format<'_, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const E: usize> Format<'a, E> {
+    pub const fn new() -> Self {
+        Self {
+            separator: ":",
+            units: None,
+            zero_pad: Self::zero_pad_all_but_first(true),
+        }
+    }
+
+    /// Sets the text that is written between two elements.
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets a text that is written directly after each element, such as `["h", "m", "s"]`.
+    pub const fn units(mut self, units: [&'a str; E]) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Sets whether all elements except the most significant one are zero-padded.
+    pub const fn zero_pad(mut self, zero_pad: bool) -> Self {
+        self.zero_pad = Self::zero_pad_all_but_first(zero_pad);
+        self
+    }
+
+    /// Sets for every element individually whether it is zero-padded.
+    pub const fn zero_pad_elements(mut self, zero_pad: [bool; E]) -> Self {
+        self.zero_pad = zero_pad;
+        self
+    }
+
+    const fn zero_pad_all_but_first(zero_pad: bool) -> [bool; E] {
+        let mut elements = [zero_pad; E];
+        if E > 0 {
+            elements[0] = false;
+        }
+        elements
+    }
+
+    /// Parses a state that was rendered according to this format, and checks every element
+    /// against its limit. Zero limits are reported before any element is parsed. Zero-padding is
+    /// optional when parsing.
+    ///
+    /// Elements are delimited by their unit if there is one, and by the separator otherwise,
+    /// so a format without both can't be parsed.
+    pub fn parse<T>(&self, s: &str, limits: [T; E]) -> Result<MixedRadixCounter<T, E>, ParseError>
+    where
+        T: FromStr + Default + Copy + PartialOrd<T>,
+    {
+        for (index, &limit) in limits.iter().enumerate() {
+            // the default is valid for every positive limit
+            digits::validate_element(index, T::default(), limit).map_err(|_| ParseError {
+                index,
+                kind: ParseErrorKind::ZeroLimit,
+            })?;
+        }
+
+        let mut elements = [T::default(); E];
+        let mut rest = s;
+        for (index, element) in elements.iter_mut().enumerate() {
+            let error = |kind| ParseError { index, kind };
+
+            if index > 0 {
+                rest = rest
+                    .strip_prefix(self.separator)
+                    .ok_or(error(ParseErrorKind::MissingSeparator))?;
+            }
+
+            let unit = self.units.map(|units| units[index]).unwrap_or_default();
+            let (text, remainder) = if !unit.is_empty() {
+                let end = rest.find(unit).ok_or(error(ParseErrorKind::MissingUnit))?;
+                (&rest[..end], &rest[end + unit.len()..])
+            } else if index + 1 < E {
+                rest.split_at(rest.find(self.separator).unwrap_or(rest.len()))
+            } else {
+                (rest, "")
+            };
+            rest = remainder;
+
+            *element = text
+                .parse()
+                .map_err(|_| error(ParseErrorKind::InvalidElement))?;
+            // the limits are valid, so this can only fail if the element is too large
+            digits::validate_element(index, *element, limits[index])
+                .map_err(|_| error(ParseErrorKind::OutOfRange))?;
+        }
+
+        if !rest.is_empty() {
+            return Err(ParseError {
+                index: E,
+                kind: ParseErrorKind::TrailingCharacters,
+            });
+        }
+        Ok(MixedRadixCounter { elements, limits })
+    }
+
+    /// Returns a value that renders the state of `counter` according to this format.
+    pub fn display<'b, T>(&self, counter: &'b MixedRadixCounter<T, E>) -> Formatted<'a, 'b, T, E> {
+        Formatted {
+            format: *self,
+            counter,
+        }
+    }
+}
+
+/// The state of a [`MixedRadixCounter`], rendered according to a [`Format`].
+///
+/// Created by [`Format::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, 'b, T, const E: usize> {
+    format: Format<'a, E>,
+    counter: &'b MixedRadixCounter<T, E>,
+}
+
+impl<T, const E: usize> Display for Formatted<'_, '_, T, E>
+where
+    T: Display + One + Sub<Output = T> + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let elements = self.counter.elements.iter();
+        for (i, (element, &limit)) in elements.zip(self.counter.limits.iter()).enumerate() {
+            if i > 0 {
+                f.write_str(self.format.separator)?;
+            }
+
+            if self.format.zero_pad[i] {
+                let width = rendered_width(limit - T::one());
+                write!(f, "{element:0>width$}")?;
+            } else {
+                write!(f, "{element}")?;
+            }
+
+            if let Some(units) = self.format.units {
+                f.write_str(units[i])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, const E: usize> Display for MixedRadixCounter<T, E>
+where
+    T: Display + One + Sub<Output = T> + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Format::new().display(self).fmt(f)
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: FromStr + Default + Copy + PartialOrd<T>,
+{
+    /// Parses a state in the default [`Format`], such as `2:073:09:36:38`, which is the
+    /// inverse of the [`Display`] implementation.
+    pub fn parse(s: &str, limits: [T; E]) -> Result<Self, ParseError> {
+        Format::new().parse(s, limits)
+    }
+}
+
+/// The reason why [`Format::parse`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// The index of the element that couldn't be parsed. For
+    /// [`ParseErrorKind::TrailingCharacters`], this is the number of elements.
+    pub index: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The separator in front of the element is missing.
+    MissingSeparator,
+    /// The unit after the element is missing.
+    MissingUnit,
+    /// The element is not a valid value of the element type.
+    InvalidElement,
+    /// The limit of the element is zero, so no element can be smaller than it.
+    ZeroLimit,
+    /// The element is not smaller than its limit.
+    OutOfRange,
+    /// There is more input after the last element.
+    TrailingCharacters,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let index = self.index;
+        match self.kind {
+            ParseErrorKind::MissingSeparator => {
+                write!(f, "missing separator before element {index}")
+            }
+            ParseErrorKind::MissingUnit => write!(f, "missing unit after element {index}"),
+            ParseErrorKind::InvalidElement => write!(f, "element {index} is invalid"),
+            ParseErrorKind::ZeroLimit => write!(f, "limit {index} is zero"),
+            ParseErrorKind::OutOfRange => {
+                write!(f, "element {index} is not smaller than its limit")
+            }
+            ParseErrorKind::TrailingCharacters => {
+                f.write_str("unexpected characters after the last element")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+fn rendered_width(value: impl Display) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    // writing into `Counter` can't fail
+    let _ = write!(counter, "{value}");
+    counter.0
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::format;
+
+    use crate::{Format, MixedRadixCounter, ParseError, ParseErrorKind};
+
+    #[test]
+    fn test_display() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u64::MAX, 365, 24, 60, 60],
+            [2, 73, 9, 36, 38],
+        )
+        .unwrap();
+
+        assert_eq!(format!("{mrc}"), "2:073:09:36:38");
+        assert_eq!(
+            format!(
+                "{}",
+                Format::new()
+                    .separator(" ")
+                    .units(["y", "d", "h", "m", "s"])
+                    .display(&mrc)
+            ),
+            "2y 073d 09h 36m 38s"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Format::new().separator("-").zero_pad(false).display(&mrc)
+            ),
+            "2-73-9-36-38"
+        );
+    }
+
+    #[test]
+    fn test_padding_width() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [10_u16, 10, 11, 1000, 1001],
+            [9, 1, 1, 1, 1],
+        )
+        .unwrap();
+        assert_eq!(format!("{mrc}"), "9:1:01:001:0001");
+        assert_eq!(
+            format!("{}", Format::new().separator("").display(&mrc)),
+            "91010010001"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Format::new()
+                    .zero_pad_elements([true, false, true, false, true])
+                    .display(&mrc)
+            ),
+            "9:1:01:1:0001"
+        );
+    }
+
+    #[test]
+    fn test_display_without_elements() {
+        let mrc = MixedRadixCounter::<u8, 0>::try_from_limits([]).unwrap();
+        assert_eq!(format!("{mrc}"), "");
+    }
+
+    #[test]
+    fn test_parse() {
+        let limits = [u64::MAX, 365, 24, 60, 60];
+        let mrc =
+            MixedRadixCounter::try_from_limits_and_elements(limits, [2, 73, 9, 36, 38]).unwrap();
+
+        assert_eq!(
+            MixedRadixCounter::parse("2:073:09:36:38", limits),
+            Ok(mrc.clone())
+        );
+        assert_eq!(
+            MixedRadixCounter::parse("2:73:9:36:38", limits),
+            Ok(mrc.clone())
+        );
+        assert_eq!(
+            Format::new()
+                .separator("")
+                .units(["y", "d", "h", "m", "s"])
+                .parse("2y73d9h36m38s", limits),
+            Ok(mrc.clone())
+        );
+        assert_eq!(
+            Format::new()
+                .units(["", "", "", "", "s"])
+                .parse("2:073:09:36:38s", limits),
+            Ok(mrc)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let limits = [24_u8, 60, 60];
+        for (input, index, kind) in [
+            ("", 0, ParseErrorKind::InvalidElement),
+            ("1:23", 2, ParseErrorKind::MissingSeparator),
+            ("1:23:", 2, ParseErrorKind::InvalidElement),
+            ("1:x:3", 1, ParseErrorKind::InvalidElement),
+            ("1:-3:3", 1, ParseErrorKind::InvalidElement),
+            ("1:300:3", 1, ParseErrorKind::InvalidElement),
+            ("24:00:00", 0, ParseErrorKind::OutOfRange),
+            ("1:23:60", 2, ParseErrorKind::OutOfRange),
+            ("1;23;59", 0, ParseErrorKind::InvalidElement),
+        ] {
+            assert_eq!(
+                MixedRadixCounter::parse(input, limits),
+                Err(ParseError { index, kind }),
+                "{input}"
+            );
+        }
+
+        for input in ["1:0:3", "1:23:3", ""] {
+            assert_eq!(
+                MixedRadixCounter::parse(input, [24_u8, 0, 60]),
+                Err(ParseError {
+                    index: 1,
+                    kind: ParseErrorKind::ZeroLimit
+                }),
+                "{input}"
+            );
+        }
+
+        let format = Format::new().separator(" ").units(["h", "m", "s"]);
+        assert_eq!(
+            format.parse("1h 23m 59", limits),
+            Err(ParseError {
+                index: 2,
+                kind: ParseErrorKind::MissingUnit
+            })
+        );
+        assert_eq!(
+            format.parse("1h 23m 59s ", limits),
+            Err(ParseError {
+                index: 3,
+                kind: ParseErrorKind::TrailingCharacters
+            })
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ParseError {
+                    index: 2,
+                    kind: ParseErrorKind::OutOfRange
+                }
+            ),
+            "element 2 is not smaller than its limit"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let limits = [3_u16, 1, 12, 101];
+        let formats = [
+            Format::new(),
+            Format::new().zero_pad(false),
+            Format::new().separator(" ").units(["a", "b", "c", "d"]),
+            Format::new().separator("").units(["a", "b", "c", "d"]),
+        ];
+        for state in MixedRadixCounter::try_from_limits(limits).unwrap() {
+            let mrc = MixedRadixCounter::try_from_limits_and_elements(limits, state).unwrap();
+            for format in formats {
+                let text = format!("{}", format.display(&mrc));
+                assert_eq!(format.parse(&text, limits), Ok(mrc.clone()), "{text}");
+            }
+        }
+    }
+}
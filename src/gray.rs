@@ -0,0 +1,232 @@
+use core::iter::FusedIterator;
+use core::ops::{Add, Rem, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::MixedRadixCounter;
+
+/// The direction in which an element changed between two states of a [`GrayStates`] iterator.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+/// An iterator over all states of a [`MixedRadixCounter`] in reflected Gray code order, so that
+/// two consecutive states differ in exactly one element by one.
+///
+/// Every item is a state together with the index of the element that changed and the direction
+/// it changed in, which is `None` for the first state.
+///
+/// Created by [`MixedRadixCounter::iter_gray`].
+#[derive(Debug, Clone)]
+pub struct GrayStates<T, const E: usize> {
+    counter: MixedRadixCounter<T, E>,
+    directions: [Direction; E],
+    /// Focus pointers as in Knuth's Algorithm H (TAOCP 7.2.1.1), indexed like `positions`.
+    focus: [usize; E],
+    /// The indices of all elements whose limit is larger than one, from the least significant
+    /// to the most significant one. Only those elements can ever change.
+    positions: [usize; E],
+    len: usize,
+    started: bool,
+    finished: bool,
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    /// Returns an iterator over all states in reflected Gray code order, starting at zero no
+    /// matter what the current state is.
+    pub fn iter_gray(&self) -> GrayStates<T, E> {
+        let mut positions = [0; E];
+        let mut len = 0;
+        for i in (0..E).rev() {
+            if self.limits[i] > T::one() {
+                positions[len] = i;
+                len += 1;
+            }
+        }
+
+        GrayStates {
+            counter: Self {
+                elements: [T::zero(); E],
+                limits: self.limits,
+            },
+            directions: [Direction::Up; E],
+            focus: core::array::from_fn(|j| j),
+            positions,
+            len,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Interprets the elements as an ordinary rank and returns the state that has this rank in
+    /// the order of [`MixedRadixCounter::iter_gray`].
+    pub fn gray_encode(&self) -> Self {
+        let mut gray = self.clone();
+        let mut odd = false;
+        for i in 0..E {
+            let (element, limit) = (self.elements[i], self.limits[i]);
+            if odd {
+                gray.elements[i] = limit - T::one() - element;
+            }
+            odd = prefix_is_odd(odd, element, limit);
+        }
+        gray
+    }
+
+    /// The inverse of [`MixedRadixCounter::gray_encode`], which returns the rank of a state in
+    /// the order of [`MixedRadixCounter::iter_gray`].
+    pub fn gray_decode(&self) -> Self {
+        let mut rank = self.clone();
+        let mut odd = false;
+        for i in 0..E {
+            let limit = self.limits[i];
+            if odd {
+                rank.elements[i] = limit - T::one() - self.elements[i];
+            }
+            odd = prefix_is_odd(odd, rank.elements[i], limit);
+        }
+        rank
+    }
+}
+
+/// Returns whether the number formed by the elements up to and including `element` is odd,
+/// given whether the number formed by the more significant elements is odd.
+///
+/// An element is reflected if the number formed by the more significant elements is odd, since
+/// its direction changes every time that number is incremented.
+fn prefix_is_odd<T>(odd: bool, element: T, limit: T) -> bool
+where
+    T: One + Zero,
+    T: Add<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    let is_odd = |value: T| value % (T::one() + T::one()) != T::zero();
+    (odd && is_odd(limit)) != is_odd(element)
+}
+
+impl<T, const E: usize> GrayStates<T, E> {
+    fn focus(&self, j: usize) -> usize {
+        // Algorithm H has one more focus pointer, which always points to itself
+        if j == self.len {
+            j
+        } else {
+            self.focus[j]
+        }
+    }
+}
+
+impl<T, const E: usize> Iterator for GrayStates<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    type Item = ([T; E], Option<(usize, Direction)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some((self.counter.elements, None));
+        }
+
+        let j = self.focus(0);
+        if j == self.len {
+            self.finished = true;
+            return None;
+        }
+        self.focus[0] = 0;
+
+        let index = self.positions[j];
+        let direction = self.directions[j];
+        let (element, limit) = (self.counter.elements[index], self.counter.limits[index]);
+        let element = match direction {
+            Direction::Up => element + T::one(),
+            Direction::Down => element - T::one(),
+        };
+        self.counter.elements[index] = element;
+
+        if element == T::zero() || element == limit - T::one() {
+            self.directions[j] = match direction {
+                Direction::Up => Direction::Down,
+                Direction::Down => Direction::Up,
+            };
+            self.focus[j] = self.focus(j + 1);
+            if j + 1 < self.len {
+                self.focus[j + 1] = j + 1;
+            }
+        }
+
+        Some((self.counter.elements, Some((index, direction))))
+    }
+}
+
+impl<T, const E: usize> FusedIterator for GrayStates<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, MixedRadixCounter};
+
+    #[test]
+    fn test_gray() {
+        let mrc = MixedRadixCounter::try_from_limits([3_u8, 1, 2, 4]).unwrap();
+
+        let mut previous = None;
+        let mut count = 0;
+        for ((state, change), rank) in mrc.iter_gray().zip(mrc.iter_states()) {
+            let rank =
+                MixedRadixCounter::try_from_limits_and_elements(*mrc.limits(), rank).unwrap();
+            let gray = rank.gray_encode();
+            assert_eq!(state, *gray);
+            assert_eq!(gray.gray_decode(), rank);
+
+            match (previous, change) {
+                (None, None) => {}
+                (Some(previous), Some((index, direction))) => {
+                    let mut expected: [u8; 4] = previous;
+                    match direction {
+                        Direction::Up => expected[index] += 1,
+                        Direction::Down => expected[index] -= 1,
+                    }
+                    assert_eq!(state, expected);
+                }
+                _ => panic!("unexpected change {change:?}"),
+            }
+            previous = Some(state);
+            count += 1;
+        }
+        assert_eq!(count, 24);
+    }
+
+    #[test]
+    fn test_gray_order() {
+        let mrc = MixedRadixCounter::try_from_limits([2_u8, 3]).unwrap();
+        let mut states = mrc.iter_gray();
+        assert_eq!(states.next(), Some(([0, 0], None)));
+        assert_eq!(states.next(), Some(([0, 1], Some((1, Direction::Up)))));
+        assert_eq!(states.next(), Some(([0, 2], Some((1, Direction::Up)))));
+        assert_eq!(states.next(), Some(([1, 2], Some((0, Direction::Up)))));
+        assert_eq!(states.next(), Some(([1, 1], Some((1, Direction::Down)))));
+        assert_eq!(states.next(), Some(([1, 0], Some((1, Direction::Down)))));
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next(), None);
+
+        let single = MixedRadixCounter::try_from_limits([1_u8, 1]).unwrap();
+        assert!(single.iter_gray().eq([([0, 0], None)]));
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{digits, InvalidValues, MixedRadixCounter};
+
+/// Mutable access to the elements of a [`MixedRadixCounter`], returned by
+/// [`MixedRadixCounter::digits_mut`].
+///
+/// The guard works on a copy of the elements, which is validated against the limits and written
+/// back into the counter when the guard is committed or dropped. If any element is invalid at
+/// that point, or if the guard is leaked, the counter keeps its previous state.
+#[derive(Debug)]
+pub struct DigitsMut<'a, T, const E: usize>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    counter: &'a mut MixedRadixCounter<T, E>,
+    elements: [T; E],
+    finished: bool,
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    pub fn digits_mut(&mut self) -> DigitsMut<'_, T, E> {
+        DigitsMut {
+            elements: self.elements,
+            counter: self,
+            finished: false,
+        }
+    }
+}
+
+impl<T, const E: usize> DigitsMut<'_, T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    /// Validates the elements, and writes them into the counter if all of them are valid.
+    pub fn commit(mut self) -> Result<(), InvalidValues<T>> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), InvalidValues<T>> {
+        self.finished = true;
+        digits::validate(&self.counter.limits, &self.elements)?;
+        self.counter.elements = self.elements;
+        Ok(())
+    }
+}
+
+impl<T, const E: usize> Deref for DigitsMut<'_, T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    type Target = [T; E];
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<T, const E: usize> DerefMut for DigitsMut<'_, T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.elements
+    }
+}
+
+impl<T, const E: usize> Drop for DigitsMut<'_, T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    fn drop(&mut self) {
+        if !self.finished {
+            // there is no way to report the error here, the caller has to use `commit` for that
+            let _ = self.finish();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InvalidValues, MixedRadixCounter};
+
+    #[test]
+    fn test_commit() {
+        let mut mrc = MixedRadixCounter::try_from_limits([3_u8, 4, 5]).unwrap();
+
+        let mut digits = mrc.digits_mut();
+        digits.copy_from_slice(&[2, 3, 4]);
+        digits.reverse();
+        assert_eq!(
+            digits.commit(),
+            Err(InvalidValues::OutOfRange {
+                index: 0,
+                element: 4,
+                limit: 3
+            })
+        );
+        assert_eq!(*mrc, [0, 0, 0]);
+
+        let mut digits = mrc.digits_mut();
+        digits.fill(2);
+        assert_eq!(digits.commit(), Ok(()));
+        assert_eq!(*mrc, [2, 2, 2]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut mrc = MixedRadixCounter::try_from_limits([3_u8, 4, 5]).unwrap();
+
+        mrc.digits_mut()[2] = 4;
+        assert_eq!(*mrc, [0, 0, 4]);
+
+        {
+            let mut digits = mrc.digits_mut();
+            digits[1] = 3;
+            digits[2] = 5;
+        }
+        assert_eq!(*mrc, [0, 0, 4]);
+    }
+
+    #[test]
+    fn test_forget() {
+        let mut mrc = MixedRadixCounter::try_from_limits([3_u8, 4]).unwrap();
+
+        let mut digits = mrc.digits_mut();
+        digits[1] = 9;
+        core::mem::forget(digits);
+        assert_eq!(*mrc, [0, 0]);
+        assert_eq!(mrc.add(1), None);
+        assert_eq!(*mrc, [0, 1]);
+    }
+}
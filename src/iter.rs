@@ -0,0 +1,284 @@
+use core::iter::FusedIterator;
+use core::ops::{Add, Div, Rem, Sub};
+
+use num_traits::{Bounded, One, Zero};
+
+use crate::MixedRadixCounter;
+
+/// An iterator over the states of a [`MixedRadixCounter`], from its current state up to and
+/// including its largest state.
+///
+/// Created by [`MixedRadixCounter::iter_states`] or by [`IntoIterator::into_iter`].
+///
+/// [`ExactSizeIterator::len`] panics if the number of remaining states doesn't fit into a
+/// `usize`, since [`Iterator::size_hint`] can't report an exact length in that case.
+#[derive(Debug, Clone)]
+pub struct States<T, const E: usize> {
+    front: MixedRadixCounter<T, E>,
+    back: MixedRadixCounter<T, E>,
+    finished: bool,
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    /// Returns an iterator over all states from the current one up to and including the
+    /// largest state, without modifying this counter.
+    pub fn iter_states(&self) -> States<T, E> {
+        let mut back = self.clone();
+        back.set_max();
+        States {
+            front: self.clone(),
+            back,
+            finished: false,
+        }
+    }
+}
+
+impl<T, const E: usize> IntoIterator for MixedRadixCounter<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+    T: Bounded + TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+    type Item = [T; E];
+    type IntoIter = States<T, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_states()
+    }
+}
+
+impl<T, const E: usize> States<T, E>
+where
+    T: One + Default,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+    usize: TryFrom<T>,
+{
+    fn remaining(&self) -> Option<usize> {
+        if self.finished {
+            return Some(0);
+        }
+        // `front` and `back` may not fit into a `usize` on their own, even if their difference
+        // does, and `back` is never smaller than `front`
+        let mut difference = self.back.clone();
+        difference.sub_counter(&self.front).ok()?;
+        difference.to_scalar::<usize>()?.checked_add(1)
+    }
+}
+
+impl<T, const E: usize> Iterator for States<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+    T: Bounded + TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+    type Item = [T; E];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let state = self.front.elements;
+        if self.front.elements == self.back.elements {
+            self.finished = true;
+        } else {
+            self.front.increment();
+        }
+        Some(state)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // jump in steps of at most `T::MAX`, since `n` may not fit into `T`
+        let mut front = self.front.clone();
+        let mut rest = n;
+        loop {
+            let step = T::try_from(rest).unwrap_or(T::max_value());
+            let carry = front.add(step);
+            if carry.is_some() || front.elements > self.back.elements {
+                self.finished = true;
+                return None;
+            }
+            let Ok(step) = usize::try_from(step) else {
+                unreachable!("the step is at most `rest`");
+            };
+            rest -= step;
+            if rest == 0 {
+                break;
+            }
+        }
+        self.front = front;
+        self.next()
+    }
+}
+
+impl<T, const E: usize> DoubleEndedIterator for States<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+    T: Bounded + TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let state = self.back.elements;
+        if self.front.elements == self.back.elements {
+            self.finished = true;
+        } else {
+            self.back.decrement();
+        }
+        Some(state)
+    }
+}
+
+impl<T, const E: usize> ExactSizeIterator for States<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+    T: Bounded + TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+}
+
+impl<T, const E: usize> FusedIterator for States<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+    T: Bounded + TryFrom<usize>,
+    usize: TryFrom<T>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_iter_states() {
+        let mrc = MixedRadixCounter::try_from_limits([2_u8, 4, 3]).unwrap();
+        let mut expected = mrc.clone();
+
+        let mut states = mrc.iter_states();
+        assert_eq!(states.len(), 24);
+        for remaining in (0..24).rev() {
+            assert_eq!(states.next(), Some(*expected));
+            assert_eq!(states.len(), remaining);
+            expected.increment();
+        }
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next_back(), None);
+
+        // the counter itself is left untouched
+        assert_eq!(*mrc, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_into_iter_from_current_state() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([2_u8, 4, 3], [1, 2, 2]).unwrap();
+        let states = mrc.into_iter();
+        assert_eq!(states.len(), 4);
+        assert!(states.eq([[1, 2, 2], [1, 3, 0], [1, 3, 1], [1, 3, 2]]));
+    }
+
+    #[test]
+    fn test_double_ended() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 2], [0, 1]).unwrap();
+
+        let mut states = mrc.iter_states();
+        assert_eq!(states.next_back(), Some([2, 1]));
+        assert_eq!(states.next(), Some([0, 1]));
+        assert_eq!(states.next_back(), Some([2, 0]));
+        assert_eq!(states.len(), 2);
+        assert_eq!(states.next(), Some([1, 0]));
+        assert_eq!(states.next_back(), Some([1, 1]));
+        assert_eq!(states.len(), 0);
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next_back(), None);
+
+        assert!(mrc
+            .iter_states()
+            .rev()
+            .eq([[2, 1], [2, 0], [1, 1], [1, 0], [0, 1]]));
+    }
+
+    #[test]
+    fn test_nth() {
+        let mrc = MixedRadixCounter::try_from_limits([u64::MAX, 365, 24, 60, 60]).unwrap();
+
+        let mut states = mrc.iter_states();
+        assert_eq!(states.nth(69_413_798), Some([2, 73, 9, 36, 38]));
+        assert_eq!(states.next(), Some([2, 73, 9, 36, 39]));
+
+        let mrc = MixedRadixCounter::try_from_limits([2_u8, 4, 3]).unwrap();
+        let mut states = mrc.iter_states();
+        assert_eq!(states.nth(1), Some([0, 0, 1]));
+        assert_eq!(states.nth(21), Some([1, 3, 2]));
+        assert_eq!(states.next(), None);
+
+        let mut states = mrc.iter_states();
+        states.next_back();
+        assert_eq!(states.nth(22), Some([1, 3, 1]));
+        let mut states = mrc.iter_states();
+        states.next_back();
+        assert_eq!(states.nth(23), None);
+        assert_eq!(states.next(), None);
+
+        // doesn't fit into a single `add`, so this jumps in several steps
+        let mrc = MixedRadixCounter::try_from_limits([u8::MAX, u8::MAX]).unwrap();
+        assert_eq!(mrc.iter_states().nth(1000), Some([3, 235]));
+        assert_eq!(mrc.iter_states().nth(255 * 255 - 1), Some([254, 254]));
+        assert_eq!(mrc.iter_states().nth(255 * 255), None);
+    }
+
+    #[test]
+    fn test_size_hint_overflow() {
+        let mrc = MixedRadixCounter::try_from_limits([u64::MAX, u64::MAX]).unwrap();
+        assert_eq!(mrc.iter_states().size_hint(), (usize::MAX, None));
+
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u64::MAX, u64::MAX],
+            [u64::MAX - 1, 3],
+        )
+        .unwrap();
+        let states = mrc.iter_states();
+        let remaining = 18_446_744_073_709_551_612;
+        assert_eq!(states.size_hint(), (remaining, Some(remaining)));
+        assert_eq!(states.len(), remaining);
+    }
+
+    #[test]
+    fn test_without_elements() {
+        let mrc = MixedRadixCounter::<u8, 0>::try_from_limits([]).unwrap();
+        let mut states = mrc.into_iter();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states.next(), Some([]));
+        assert_eq!(states.next(), None);
+    }
+}
@@ -1,11 +1,49 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::ops::{Add, Deref, Div, Rem, Sub};
+use core::slice::SliceIndex;
 
 use num_traits::{One, Zero};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct InvalidValues;
+pub use calendar::{DateTime, Gregorian};
+pub use combination::Combinations;
+pub use dependent::{DependentCounter, DependentStates, Limits};
+pub use duration::DurationUnits;
+#[cfg(feature = "alloc")]
+pub use dynamic::DynMixedRadixCounter;
+pub use error::{DurationError, InvalidValues, LimitsMismatch};
+pub use format::{Format, Formatted, ParseError, ParseErrorKind};
+pub use gray::{Direction, GrayStates};
+pub use guard::DigitsMut;
+pub use iter::States;
+pub use search::{Constrained, Pruned};
+pub use timecode::{FrameRate, Timecode};
+pub use tuple::{DigitTuple, TupleCounter};
+
+mod calendar;
+mod combination;
+mod dependent;
+mod digits;
+mod duration;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod error;
+mod format;
+mod gray;
+mod guard;
+mod iter;
+mod ops;
+mod overflow;
+mod permutation;
+mod scalar;
+mod search;
+#[cfg(feature = "serde")]
+mod serialization;
+mod timecode;
+mod tuple;
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct MixedRadixCounter<T, const E: usize> {
@@ -28,16 +66,25 @@ where
     T: PartialOrd<T> + Copy,
 {
     pub fn increment(&mut self) -> Option<T> {
-        for i in (0..E).rev() {
-            // this can't overflow since `elements[i] < limit <= T::MAX`
-            let sum = self.elements[i].add(T::one());
-            if sum < self.limits[i] {
-                self.elements[i] = sum;
-                return None;
-            }
-            self.elements[i] = T::default();
-        }
-        Some(T::one())
+        digits::increment(&mut self.elements, &self.limits)
+    }
+
+    /// Increments the element at `index` and resets all less significant elements to zero,
+    /// which skips every state that shares the current elements up to `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn advance_at(&mut self, index: usize) -> Option<T> {
+        digits::advance_at(&mut self.elements, &self.limits, index)
+    }
+
+    pub fn decrement(&mut self) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T>,
+    {
+        digits::decrement(&mut self.elements, &self.limits)
     }
 
     pub fn add(&mut self, value: T) -> Option<T>
@@ -45,27 +92,43 @@ where
         T: Zero,
         T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
     {
-        let mut carry = value;
-        for i in (0..E).rev() {
-            let limit = self.limits[i];
-            let sum = self.elements[i].add(carry); // TODO: do we need to worry about overflows here?
+        digits::add(&mut self.elements, &self.limits, value)
+    }
 
-            if sum < limit {
-                self.elements[i] = sum;
-                return None;
-            } else {
-                let new_value = sum % limit;
-                carry = sum / limit;
+    pub fn sub(&mut self, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        digits::sub(&mut self.elements, &self.limits, value)
+    }
 
-                self.elements[i] = new_value;
+    /// Adds `value` at the element at `index`, as if it was the least significant one. Less
+    /// significant elements are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn add_at(&mut self, index: usize, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        digits::add(&mut self.elements[..=index], &self.limits[..=index], value)
+    }
 
-                // if this is the last element and there is still a carry, return the overflow
-                if i == 0 && carry != T::zero() {
-                    return Some(carry);
-                }
-            }
-        }
-        None
+    /// Subtracts `value` at the element at `index`, as if it was the least significant one. Less
+    /// significant elements are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn sub_at(&mut self, index: usize, value: T) -> Option<T>
+    where
+        T: Zero,
+        T: Sub<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T>,
+    {
+        digits::sub(&mut self.elements[..=index], &self.limits[..=index], value)
     }
 }
 
@@ -73,7 +136,7 @@ impl<T, const E: usize> TryFrom<[T; E]> for MixedRadixCounter<T, E>
 where
     T: Default + Copy + PartialOrd<T>,
 {
-    type Error = InvalidValues;
+    type Error = InvalidValues<T>;
 
     fn try_from(value: [T; E]) -> Result<Self, Self::Error> {
         Self::try_from_limits(value)
@@ -84,30 +147,73 @@ impl<T, const E: usize> MixedRadixCounter<T, E>
 where
     T: Default + Copy + PartialOrd<T>,
 {
-    pub fn try_from_limits(limits: [T; E]) -> Result<Self, InvalidValues> {
+    pub fn try_from_limits(limits: [T; E]) -> Result<Self, InvalidValues<T>> {
         Self::try_from_limits_and_elements(limits, [T::default(); E])
     }
 
     pub fn try_from_limits_and_elements(
         limits: [T; E],
         elements: [T; E],
-    ) -> Result<Self, InvalidValues> {
-        elements
-            .iter()
-            .zip(limits.iter())
-            .try_for_each(|(&element, &limit)| {
-                if element >= limit {
-                    return Err(InvalidValues);
-                }
-                Ok(())
-            })?;
+    ) -> Result<Self, InvalidValues<T>> {
+        digits::validate(&limits, &elements)?;
         Ok(Self { elements, limits })
     }
+
+    /// Sets the element at `index`, unless `index` is out of bounds or `value` is not smaller
+    /// than its limit.
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), InvalidValues<T>> {
+        let Some(&limit) = self.limits.get(index) else {
+            return Err(InvalidValues::IndexOutOfBounds { index, len: E });
+        };
+        digits::validate_element(index, value, limit)?;
+        self.elements[index] = value;
+        Ok(())
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E> {
+    /// Returns an element or a subslice of elements, like [`slice::get`].
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.elements.get(index)
+    }
+
+    pub fn limit(&self, index: usize) -> Option<&T> {
+        self.limits.get(index)
+    }
+
+    pub fn limits(&self) -> &[T; E] {
+        &self.limits
+    }
+
+    /// Returns the limits and the elements, in the same order that
+    /// [`MixedRadixCounter::try_from_limits_and_elements`] takes them.
+    pub fn into_parts(self) -> ([T; E], [T; E]) {
+        (self.limits, self.elements)
+    }
+
+    /// Returns an iterator over all elements together with their limits,
+    /// from the most significant element to the least significant one.
+    pub fn digits(&self) -> impl DoubleEndedIterator<Item = (T, T)> + ExactSizeIterator + '_
+    where
+        T: Copy,
+    {
+        self.elements
+            .iter()
+            .copied()
+            .zip(self.limits.iter().copied())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::MixedRadixCounter;
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::{InvalidValues, MixedRadixCounter};
 
     #[test]
     fn test_increment() {
@@ -200,5 +306,287 @@ mod tests {
 
         mrc.increment();
         assert_eq!(*mrc, [1, 1]);
+
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u8::MAX, u8::MAX],
+            [u8::MAX - 1, u8::MAX - 1],
+        )
+        .unwrap();
+        assert_eq!(mrc.add(u8::MAX), Some(1));
+        assert_eq!(*mrc, [0, u8::MAX - 1]);
+    }
+
+    fn assert_matches_scalar<const E: usize>(
+        limits: [u8; E],
+        elements: [u8; E],
+        value: u8,
+        subtract: bool,
+    ) {
+        let capacity = limits.iter().fold(1_i64, |acc, &x| acc * x as i64);
+        let scalar = elements
+            .iter()
+            .zip(limits.iter())
+            .fold(0_i64, |acc, (&x, &limit)| acc * limit as i64 + x as i64);
+        let result = if subtract {
+            scalar - value as i64
+        } else {
+            scalar + value as i64
+        };
+
+        let mut expected = [0_u8; E];
+        let mut rest = result.rem_euclid(capacity);
+        for i in (0..E).rev() {
+            expected[i] = (rest % limits[i] as i64) as u8;
+            rest /= limits[i] as i64;
+        }
+        let expected_carry = match result.div_euclid(capacity).unsigned_abs() {
+            0 => None,
+            carry => Some(carry as u8),
+        };
+
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements(limits, elements).unwrap();
+        let carry = if subtract {
+            mrc.sub(value)
+        } else {
+            mrc.add(value)
+        };
+        assert_eq!(
+            (*mrc, carry),
+            (expected, expected_carry),
+            "limits: {limits:?}, elements: {elements:?}, value: {value}, subtract: {subtract}"
+        );
+    }
+
+    #[test]
+    fn test_exhaustive_single_digit() {
+        for limit in 1..=u8::MAX {
+            for element in 0..limit {
+                for value in 0..=u8::MAX {
+                    assert_matches_scalar([limit], [element], value, false);
+                    assert_matches_scalar([limit], [element], value, true);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exhaustive_two_digits() {
+        const LIMITS: [u8; 8] = [1, 2, 3, 7, 16, 128, 254, u8::MAX];
+
+        for high_limit in LIMITS {
+            for low_limit in LIMITS {
+                for high in [0, high_limit / 2, high_limit - 1] {
+                    for low in [0, low_limit / 2, low_limit - 1] {
+                        for value in 0..=u8::MAX {
+                            let limits = [high_limit, low_limit];
+                            assert_matches_scalar(limits, [high, low], value, false);
+                            assert_matches_scalar(limits, [high, low], value, true);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exhaustive_three_digits() {
+        for limits in [[2_u8, 3, 5], [u8::MAX, 1, u8::MAX], [3, u8::MAX, 2]] {
+            let mut elements = MixedRadixCounter::try_from_limits(limits).unwrap();
+            loop {
+                for value in 0..=u8::MAX {
+                    assert_matches_scalar(limits, *elements, value, false);
+                    assert_matches_scalar(limits, *elements, value, true);
+                }
+                if elements.increment().is_some() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_without_elements() {
+        let mut mrc = MixedRadixCounter::<u8, 0>::try_from_limits([]).unwrap();
+        assert_eq!(mrc.add(0), None);
+        assert_eq!(mrc.add(5), Some(5));
+        assert_eq!(mrc.sub(5), Some(5));
+        assert_eq!(mrc.decrement(), Some(1));
+    }
+
+    #[test]
+    fn test_decrement() {
+        let mut mrc = MixedRadixCounter::try_from_limits([2_u8, 4, 3]).unwrap();
+        assert_eq!(mrc.decrement(), Some(1));
+        assert_eq!(*mrc, [1, 3, 2]);
+
+        for expected_elements in [
+            [1, 3, 1],
+            [1, 3, 0],
+            [1, 2, 2],
+            [1, 2, 1],
+            [1, 2, 0],
+            [1, 1, 2],
+            [1, 1, 1],
+            [1, 1, 0],
+            [1, 0, 2],
+            [1, 0, 1],
+            [1, 0, 0],
+            [0, 3, 2],
+            [0, 3, 1],
+            [0, 3, 0],
+            [0, 2, 2],
+            [0, 2, 1],
+            [0, 2, 0],
+            [0, 1, 2],
+            [0, 1, 1],
+            [0, 1, 0],
+            [0, 0, 2],
+            [0, 0, 1],
+            [0, 0, 0],
+        ] {
+            assert!(mrc.decrement().is_none());
+            assert_eq!(*mrc, expected_elements);
+        }
+    }
+
+    #[test]
+    fn test_large_sub() {
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u64::MAX, 365, 24, 60, 60, 1000],
+            [0, 0, 19, 16, 53, 798],
+        )
+        .unwrap();
+
+        assert_eq!(mrc.sub(69_413_798), None);
+        assert_eq!(*mrc, [0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(mrc.sub(1), Some(1));
+        assert_eq!(*mrc, [u64::MAX - 1, 364, 23, 59, 59, 999]);
+    }
+
+    #[test]
+    fn test_borrow_return() {
+        for (value, expected_borrow) in [(1, 1), (2, 1), (3, 2), (4, 2), (9, 5)] {
+            let mut mrc = MixedRadixCounter::try_from_limits([2_u8]).unwrap();
+            assert_eq!(mrc.sub(value), Some(expected_borrow));
+        }
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert_eq!(
+            MixedRadixCounter::try_from_limits([3_u8, 0, 2]),
+            Err(InvalidValues::ZeroLimit { index: 1 })
+        );
+        assert_eq!(
+            MixedRadixCounter::try_from_limits_and_elements([3_u8, 4, 2], [2, 4, 2]),
+            Err(InvalidValues::OutOfRange {
+                index: 1,
+                element: 4,
+                limit: 4
+            })
+        );
+        assert_eq!(
+            MixedRadixCounter::try_from_limits_and_elements([3_u8, 0], [3, 0]),
+            Err(InvalidValues::OutOfRange {
+                index: 0,
+                element: 3,
+                limit: 3
+            })
+        );
+
+        assert_eq!(
+            InvalidValues::ZeroLimit::<u8> { index: 1 }.to_string(),
+            "limit 1 is zero"
+        );
+        assert_eq!(
+            InvalidValues::OutOfRange {
+                index: 1,
+                element: 4,
+                limit: 4
+            }
+            .to_string(),
+            "element 1 is 4, which is not smaller than its limit 4"
+        );
+    }
+
+    #[test]
+    fn test_accessors() {
+        let mut mrc =
+            MixedRadixCounter::try_from_limits_and_elements([3_u8, 4, 5], [2, 1, 0]).unwrap();
+
+        assert_eq!(mrc.get(1), Some(&1));
+        assert_eq!(mrc.get(1..), Some(&[1, 0][..]));
+        assert_eq!(mrc.get(3), None);
+        assert_eq!(mrc.limit(2), Some(&5));
+        assert_eq!(mrc.limit(3), None);
+        assert_eq!(mrc.limits(), &[3, 4, 5]);
+        assert!(mrc.digits().eq([(2, 3), (1, 4), (0, 5)]));
+        assert!(mrc.digits().rev().eq([(0, 5), (1, 4), (2, 3)]));
+
+        assert_eq!(mrc.set(2, 4), Ok(()));
+        assert_eq!(*mrc, [2, 1, 4]);
+        assert_eq!(
+            mrc.set(1, 4),
+            Err(InvalidValues::OutOfRange {
+                index: 1,
+                element: 4,
+                limit: 4
+            })
+        );
+        assert_eq!(*mrc, [2, 1, 4]);
+
+        assert_eq!(mrc.into_parts(), ([3, 4, 5], [2, 1, 4]));
+    }
+
+    #[test]
+    fn test_set_out_of_bounds() {
+        let mut mrc = MixedRadixCounter::try_from_limits([3_u8, 4, 5]).unwrap();
+        assert_eq!(
+            mrc.set(3, 0),
+            Err(InvalidValues::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(*mrc, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_add_at() {
+        let mut mrc =
+            MixedRadixCounter::try_from_limits_and_elements([u8::MAX, 24, 60, 60], [0, 22, 59, 59])
+                .unwrap();
+
+        assert_eq!(mrc.add_at(1, 3), None);
+        assert_eq!(*mrc, [1, 1, 59, 59]);
+        assert_eq!(mrc.add_at(2, 121), None);
+        assert_eq!(*mrc, [1, 4, 0, 59]);
+        assert_eq!(mrc.add_at(0, u8::MAX), Some(1));
+        assert_eq!(*mrc, [1, 4, 0, 59]);
+
+        assert_eq!(mrc.sub_at(1, 29), Some(1));
+        assert_eq!(*mrc, [u8::MAX - 1, 23, 0, 59]);
+        assert_eq!(mrc.sub_at(2, 1), None);
+        assert_eq!(*mrc, [u8::MAX - 1, 22, 59, 59]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_at_out_of_bounds() {
+        let mut mrc = MixedRadixCounter::try_from_limits([3_u8, 4, 5]).unwrap();
+        mrc.add_at(3, 1);
+    }
+
+    #[test]
+    fn test_advance_at() {
+        let mut mrc =
+            MixedRadixCounter::try_from_limits_and_elements([3_u8, 4, 5], [1, 2, 3]).unwrap();
+
+        assert_eq!(mrc.advance_at(1), None);
+        assert_eq!(*mrc, [1, 3, 0]);
+        assert_eq!(mrc.advance_at(1), None);
+        assert_eq!(*mrc, [2, 0, 0]);
+        assert_eq!(mrc.advance_at(2), None);
+        assert_eq!(*mrc, [2, 0, 1]);
+        assert_eq!(mrc.advance_at(0), Some(1));
+        assert_eq!(*mrc, [0, 0, 0]);
     }
 }
@@ -0,0 +1,326 @@
+use core::ops::{Add, AddAssign, Div, Rem, Sub, SubAssign};
+
+use num_traits::{One, Zero};
+
+use crate::{digits, LimitsMismatch, MixedRadixCounter};
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    /// Adds the state of `other` element by element, and returns the carry out of the most
+    /// significant element like [`MixedRadixCounter::add`] does.
+    ///
+    /// Fails without modifying this counter if the limits of both counters differ.
+    pub fn add_counter(&mut self, other: &Self) -> Result<Option<T>, LimitsMismatch> {
+        if self.limits != other.limits {
+            return Err(LimitsMismatch);
+        }
+        Ok(digits::add_elements(
+            &mut self.elements,
+            &other.elements,
+            &self.limits,
+        ))
+    }
+
+    /// Subtracts the state of `other` element by element, and returns the borrow out of the most
+    /// significant element like [`MixedRadixCounter::sub`] does.
+    ///
+    /// Fails without modifying this counter if the limits of both counters differ.
+    pub fn sub_counter(&mut self, other: &Self) -> Result<Option<T>, LimitsMismatch> {
+        if self.limits != other.limits {
+            return Err(LimitsMismatch);
+        }
+        Ok(digits::sub_elements(
+            &mut self.elements,
+            &other.elements,
+            &self.limits,
+        ))
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One + Zero,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    /// Multiplies the state by `factor`, and returns the carry out of the most significant
+    /// element like [`MixedRadixCounter::add`] does.
+    ///
+    /// This only works on the elements, so it doesn't overflow, even if the capacity of the
+    /// counter is far larger than `T::MAX`.
+    pub fn mul(&mut self, factor: T) -> Option<T> {
+        digits::mul(&mut self.elements, &self.limits, factor)
+    }
+
+    /// Divides the state by `divisor` using long division from the most significant element down,
+    /// and returns the quotient as a counter with the same limits, as well as the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: T) -> (Self, T) {
+        let mut quotient = self.clone();
+        let remainder = digits::div_rem(&mut quotient.elements, &self.limits, divisor);
+        (quotient, remainder)
+    }
+}
+
+/// Wraps around at the capacity of the counter.
+///
+/// # Panics
+///
+/// Panics if the limits of both counters differ, use [`MixedRadixCounter::add_counter`] to
+/// handle that case.
+impl<T, const E: usize> AddAssign<&Self> for MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_counter(rhs)
+            .expect("counters must have the same limits");
+    }
+}
+
+/// Wraps around at zero.
+///
+/// # Panics
+///
+/// Panics if the limits of both counters differ, use [`MixedRadixCounter::sub_counter`] to
+/// handle that case.
+impl<T, const E: usize> SubAssign<&Self> for MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_counter(rhs)
+            .expect("counters must have the same limits");
+    }
+}
+
+impl<T, const E: usize> AddAssign for MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<T, const E: usize> SubAssign for MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Sub<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LimitsMismatch, MixedRadixCounter};
+
+    fn counter(elements: [u8; 4]) -> MixedRadixCounter<u8, 4> {
+        MixedRadixCounter::try_from_limits_and_elements([u8::MAX, 24, 60, 60], elements).unwrap()
+    }
+
+    #[test]
+    fn test_add_counter() {
+        let mut timestamp = counter([3, 23, 45, 10]);
+        assert_eq!(timestamp.add_counter(&counter([0, 1, 30, 0])), Ok(None));
+        assert_eq!(*timestamp, [4, 1, 15, 10]);
+
+        assert_eq!(
+            timestamp.add_counter(&counter([250, 22, 44, 50])),
+            Ok(Some(1))
+        );
+        assert_eq!(*timestamp, [0, 0, 0, 0]);
+
+        let mut max = counter([254, 23, 59, 59]);
+        assert_eq!(max.add_counter(&counter([254, 23, 59, 59])), Ok(Some(1)));
+        assert_eq!(*max, [254, 23, 59, 58]);
+    }
+
+    #[test]
+    fn test_sub_counter() {
+        let mut timestamp = counter([4, 1, 15, 10]);
+        assert_eq!(timestamp.sub_counter(&counter([0, 1, 30, 0])), Ok(None));
+        assert_eq!(*timestamp, [3, 23, 45, 10]);
+
+        assert_eq!(
+            timestamp.sub_counter(&counter([3, 23, 45, 11])),
+            Ok(Some(1))
+        );
+        assert_eq!(*timestamp, [254, 23, 59, 59]);
+
+        let mut zero = counter([0, 0, 0, 0]);
+        assert_eq!(zero.sub_counter(&counter([254, 23, 59, 59])), Ok(Some(1)));
+        assert_eq!(*zero, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let limits = [3_u8, 5, 2];
+        let states = MixedRadixCounter::try_from_limits(limits).unwrap();
+        for lhs in states.clone() {
+            for rhs in states.clone() {
+                let lhs = MixedRadixCounter::try_from_limits_and_elements(limits, lhs).unwrap();
+                let rhs = MixedRadixCounter::try_from_limits_and_elements(limits, rhs).unwrap();
+                let lhs_scalar = lhs.to_scalar::<u8>().unwrap();
+                let rhs_scalar = rhs.to_scalar::<u8>().unwrap();
+
+                let (expected, carry) =
+                    MixedRadixCounter::from_scalar(limits, lhs_scalar + rhs_scalar).unwrap();
+                let mut sum = lhs.clone();
+                assert_eq!(sum.add_counter(&rhs), Ok((carry != 0).then_some(1)));
+                assert_eq!(sum, expected);
+                sum = lhs.clone();
+                sum += rhs.clone();
+                assert_eq!(sum, expected);
+
+                let (expected, _) =
+                    MixedRadixCounter::from_scalar(limits, 30 + lhs_scalar - rhs_scalar).unwrap();
+                let mut difference = lhs.clone();
+                assert_eq!(
+                    difference.sub_counter(&rhs),
+                    Ok((lhs_scalar < rhs_scalar).then_some(1))
+                );
+                assert_eq!(difference, expected);
+                difference = lhs;
+                difference -= rhs;
+                assert_eq!(difference, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut timestamp = counter([3, 23, 45, 10]);
+        timestamp += counter([0, 1, 30, 0]);
+        assert_eq!(*timestamp, [4, 1, 15, 10]);
+        timestamp -= &counter([0, 0, 15, 11]);
+        assert_eq!(*timestamp, [4, 0, 59, 59]);
+        timestamp += counter([0, 0, 0, 1]);
+        assert_eq!(*timestamp, [4, 1, 0, 0]);
+        timestamp -= counter([4, 1, 0, 0]);
+        assert_eq!(*timestamp, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_inherent_methods_with_ops_in_scope() {
+        // the operator traits must not shadow the inherent `add` and `sub`
+        #[allow(unused_imports)]
+        use core::ops::{Add, Sub};
+
+        let mut mrc = MixedRadixCounter::try_from_limits([10_u8, 10]).unwrap();
+        assert_eq!(mrc.add(5), None);
+        assert_eq!(*mrc, [0, 5]);
+        assert_eq!(mrc.sub(6), Some(1));
+        assert_eq!(*mrc, [9, 9]);
+    }
+
+    #[test]
+    fn test_limits_mismatch() {
+        let mut lhs = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [1, 2]).unwrap();
+        let rhs = MixedRadixCounter::try_from_limits_and_elements([3_u8, 5], [1, 2]).unwrap();
+
+        assert_eq!(lhs.add_counter(&rhs), Err(LimitsMismatch));
+        assert_eq!(lhs.sub_counter(&rhs), Err(LimitsMismatch));
+        assert_eq!(*lhs, [1, 2]);
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut duration = counter([0, 1, 30, 0]);
+        assert_eq!(duration.mul(3), None);
+        assert_eq!(*duration, [0, 4, 30, 0]);
+
+        assert_eq!(duration.mul(u8::MAX), None);
+        assert_eq!(*duration, [47, 19, 30, 0]);
+
+        assert_eq!(duration.mul(6), Some(1));
+        assert_eq!(*duration, [31, 21, 0, 0]);
+
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u64::MAX, u64::MAX, u64::MAX],
+            [0, u64::MAX - 1, u64::MAX - 1],
+        )
+        .unwrap();
+        assert_eq!(mrc.mul(u64::MAX), None);
+        assert_eq!(*mrc, [u64::MAX - 1, u64::MAX - 1, 0]);
+        assert_eq!(mrc.mul(u64::MAX), Some(u64::MAX - 1));
+        assert_eq!(*mrc, [u64::MAX - 1, 0, 0]);
+
+        let mut zero = MixedRadixCounter::try_from_limits_and_elements([3_u8, 5], [2, 4]).unwrap();
+        assert_eq!(zero.mul(0), None);
+        assert_eq!(*zero, [0, 0]);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let (quotient, remainder) = counter([0, 4, 30, 1]).div_rem(3);
+        assert_eq!(*quotient, [0, 1, 30, 0]);
+        assert_eq!(remainder, 1);
+
+        let (quotient, remainder) = counter([254, 23, 59, 59]).div_rem(7);
+        assert_eq!(*quotient, [36, 10, 17, 8]);
+        assert_eq!(remainder, 3);
+
+        let limits = [u64::MAX, u64::MAX, u64::MAX];
+        let mrc =
+            MixedRadixCounter::try_from_limits_and_elements(limits, [u64::MAX - 1, 0, 0]).unwrap();
+        let (quotient, remainder) = mrc.div_rem(u64::MAX - 1);
+        assert_eq!(*quotient, [1, 0, 0]);
+        assert_eq!(remainder, 0);
+
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(limits, [5, 0, 7]).unwrap();
+        let (quotient, remainder) = mrc.div_rem(u64::MAX);
+        assert_eq!(*quotient, [0, 5, 0]);
+        assert_eq!(remainder, 7);
+    }
+
+    #[test]
+    fn test_mul_div_rem_match_scalar() {
+        let limits = [u8::MAX, 7, 200];
+        let capacity = 255 * 7 * 200_u32;
+        for scalar in (0..capacity).step_by(997) {
+            let (mrc, _) = MixedRadixCounter::from_scalar(limits, scalar).unwrap();
+            for operand in 1..=u8::MAX {
+                let mut product = mrc.clone();
+                let carry = product.mul(operand);
+                let expected = scalar * operand as u32;
+                assert_eq!(product.to_scalar::<u32>(), Some(expected % capacity));
+                assert_eq!(carry.unwrap_or(0) as u32, expected / capacity);
+
+                let (quotient, remainder) = mrc.div_rem(operand);
+                assert_eq!(quotient.to_scalar::<u32>(), Some(scalar / operand as u32));
+                assert_eq!(remainder as u32, scalar % operand as u32);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_by_zero() {
+        let _ = counter([1, 2, 3, 4]).div_rem(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_operator_limits_mismatch() {
+        let mut lhs = MixedRadixCounter::try_from_limits([3_u8, 4]).unwrap();
+        let rhs = MixedRadixCounter::try_from_limits([3_u8, 5]).unwrap();
+        lhs += rhs;
+    }
+}
@@ -0,0 +1,215 @@
+use core::ops::{Add, Div, Rem, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::MixedRadixCounter;
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One + Zero + Default,
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>,
+    T: PartialOrd<T> + Copy,
+{
+    /// Adds `value`, or leaves the counter untouched and returns `None` if that would overflow.
+    #[must_use]
+    pub fn checked_add(&mut self, value: T) -> Option<()> {
+        self.checked(|mrc| mrc.add(value))
+    }
+
+    /// Adds `value`, wrapping around at the capacity of the counter.
+    pub fn wrapping_add(&mut self, value: T) {
+        self.add(value);
+    }
+
+    /// Adds `value`, stopping at the largest state if that would overflow.
+    pub fn saturating_add(&mut self, value: T) {
+        if self.add(value).is_some() {
+            self.set_max();
+        }
+    }
+
+    /// Adds `value`, wrapping around at the capacity of the counter,
+    /// and returns whether an overflow occurred.
+    #[must_use]
+    pub fn overflowing_add(&mut self, value: T) -> bool {
+        self.add(value).is_some()
+    }
+
+    /// Subtracts `value`, or leaves the counter untouched and returns `None` if that would
+    /// underflow.
+    #[must_use]
+    pub fn checked_sub(&mut self, value: T) -> Option<()> {
+        self.checked(|mrc| mrc.sub(value))
+    }
+
+    /// Subtracts `value`, wrapping around at zero.
+    pub fn wrapping_sub(&mut self, value: T) {
+        self.sub(value);
+    }
+
+    /// Subtracts `value`, stopping at zero if that would underflow.
+    pub fn saturating_sub(&mut self, value: T) {
+        if self.sub(value).is_some() {
+            self.set_min();
+        }
+    }
+
+    /// Subtracts `value`, wrapping around at zero, and returns whether an underflow occurred.
+    #[must_use]
+    pub fn overflowing_sub(&mut self, value: T) -> bool {
+        self.sub(value).is_some()
+    }
+
+    /// Increments the counter, or leaves it untouched and returns `None` if it is already at
+    /// the largest state.
+    #[must_use]
+    pub fn checked_increment(&mut self) -> Option<()> {
+        self.checked(Self::increment)
+    }
+
+    /// Increments the counter, wrapping around to zero after the largest state.
+    pub fn wrapping_increment(&mut self) {
+        self.increment();
+    }
+
+    /// Increments the counter, unless it is already at the largest state.
+    pub fn saturating_increment(&mut self) {
+        if self.increment().is_some() {
+            self.set_max();
+        }
+    }
+
+    /// Increments the counter, wrapping around to zero after the largest state,
+    /// and returns whether an overflow occurred.
+    #[must_use]
+    pub fn overflowing_increment(&mut self) -> bool {
+        self.increment().is_some()
+    }
+
+    /// Decrements the counter, or leaves it untouched and returns `None` if it is already zero.
+    #[must_use]
+    pub fn checked_decrement(&mut self) -> Option<()> {
+        self.checked(Self::decrement)
+    }
+
+    /// Decrements the counter, wrapping around to the largest state after zero.
+    pub fn wrapping_decrement(&mut self) {
+        self.decrement();
+    }
+
+    /// Decrements the counter, unless it is already zero.
+    pub fn saturating_decrement(&mut self) {
+        if self.decrement().is_some() {
+            self.set_min();
+        }
+    }
+
+    /// Decrements the counter, wrapping around to the largest state after zero,
+    /// and returns whether an underflow occurred.
+    #[must_use]
+    pub fn overflowing_decrement(&mut self) -> bool {
+        self.decrement().is_some()
+    }
+
+    fn checked(&mut self, op: impl FnOnce(&mut Self) -> Option<T>) -> Option<()> {
+        let previous = self.elements;
+        if op(self).is_some() {
+            self.elements = previous;
+            return None;
+        }
+        Some(())
+    }
+
+    pub(crate) fn set_min(&mut self) {
+        self.elements = [T::zero(); E];
+    }
+
+    pub(crate) fn set_max(&mut self) {
+        for (element, &limit) in self.elements.iter_mut().zip(self.limits.iter()) {
+            *element = limit - T::one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_checked() {
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [2, 1]).unwrap();
+
+        assert_eq!(mrc.checked_add(3), None);
+        assert_eq!(*mrc, [2, 1]);
+        assert_eq!(mrc.checked_add(2), Some(()));
+        assert_eq!(*mrc, [2, 3]);
+        assert_eq!(mrc.checked_increment(), None);
+        assert_eq!(*mrc, [2, 3]);
+
+        assert_eq!(mrc.checked_sub(12), None);
+        assert_eq!(*mrc, [2, 3]);
+        assert_eq!(mrc.checked_sub(11), Some(()));
+        assert_eq!(*mrc, [0, 0]);
+        assert_eq!(mrc.checked_decrement(), None);
+        assert_eq!(*mrc, [0, 0]);
+
+        assert_eq!(mrc.checked_increment(), Some(()));
+        assert_eq!(*mrc, [0, 1]);
+        assert_eq!(mrc.checked_decrement(), Some(()));
+        assert_eq!(*mrc, [0, 0]);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [2, 1]).unwrap();
+
+        mrc.wrapping_add(3);
+        assert_eq!(*mrc, [0, 0]);
+        mrc.wrapping_decrement();
+        assert_eq!(*mrc, [2, 3]);
+        mrc.wrapping_increment();
+        assert_eq!(*mrc, [0, 0]);
+        mrc.wrapping_sub(13);
+        assert_eq!(*mrc, [2, 3]);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [2, 1]).unwrap();
+
+        mrc.saturating_add(1);
+        assert_eq!(*mrc, [2, 2]);
+        mrc.saturating_add(200);
+        assert_eq!(*mrc, [2, 3]);
+        mrc.saturating_increment();
+        assert_eq!(*mrc, [2, 3]);
+
+        mrc.saturating_sub(1);
+        assert_eq!(*mrc, [2, 2]);
+        mrc.saturating_sub(200);
+        assert_eq!(*mrc, [0, 0]);
+        mrc.saturating_decrement();
+        assert_eq!(*mrc, [0, 0]);
+    }
+
+    #[test]
+    fn test_overflowing() {
+        let mut mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [2, 1]).unwrap();
+
+        assert!(!mrc.overflowing_add(2));
+        assert_eq!(*mrc, [2, 3]);
+        assert!(mrc.overflowing_increment());
+        assert_eq!(*mrc, [0, 0]);
+        assert!(mrc.overflowing_decrement());
+        assert_eq!(*mrc, [2, 3]);
+        assert!(!mrc.overflowing_sub(11));
+        assert_eq!(*mrc, [0, 0]);
+        assert!(mrc.overflowing_sub(1));
+        assert_eq!(*mrc, [2, 3]);
+        assert!(!mrc.overflowing_decrement());
+        assert_eq!(*mrc, [2, 2]);
+        assert!(!mrc.overflowing_increment());
+        assert!(mrc.overflowing_add(1));
+        assert_eq!(*mrc, [0, 0]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::MixedRadixCounter;
+
+// Counters with the limits `[E, E - 1, ..., 1]` represent numbers in the factorial number
+// system. Their states are the Lehmer codes of the permutations of `E` items, in lexicographic
+// order of the permutations, so iterating, ranking and unranking them does the same for
+// permutations.
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+    T: TryFrom<usize>,
+{
+    /// Creates a counter with the limits `[E, E - 1, ..., 1]`, or `None` if `E` doesn't fit into
+    /// `T`.
+    pub fn factoradic() -> Option<Self> {
+        let mut limits = [T::default(); E];
+        for (i, limit) in limits.iter_mut().enumerate() {
+            *limit = T::try_from(E - i).ok()?;
+        }
+        Some(Self {
+            elements: [T::default(); E],
+            limits,
+        })
+    }
+
+    /// Creates a factoradic counter from the Lehmer code of `permutation`, or returns `None` if
+    /// `permutation` doesn't contain every number from `0` to `E - 1` exactly once.
+    pub fn from_permutation(permutation: &[usize; E]) -> Option<Self> {
+        let mut seen = [false; E];
+        for &item in permutation {
+            if *seen.get(item)? {
+                return None;
+            }
+            seen[item] = true;
+        }
+
+        let mut counter = Self::factoradic()?;
+        for (i, &item) in permutation.iter().enumerate() {
+            let smaller_after = permutation[i + 1..].iter().filter(|&&x| x < item).count();
+            counter.elements[i] = T::try_from(smaller_after).ok()?;
+        }
+        Some(counter)
+    }
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Copy,
+    usize: TryFrom<T>,
+{
+    /// Returns the permutation whose Lehmer code is given by the elements, or `None` if the
+    /// element at some `index` isn't smaller than `E - index`, which can't happen for a
+    /// factoradic counter.
+    pub fn to_permutation(&self) -> Option<[usize; E]> {
+        let mut remaining: [usize; E] = core::array::from_fn(|i| i);
+        let mut permutation = [0; E];
+        for (i, &element) in self.elements.iter().enumerate() {
+            let index = usize::try_from(element).ok()?;
+            let remaining = &mut remaining[..E - i];
+            permutation[i] = *remaining.get(index)?;
+            remaining[index..].rotate_left(1);
+        }
+        Some(permutation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_factoradic() {
+        let mrc = MixedRadixCounter::<u8, 4>::factoradic().unwrap();
+        assert_eq!(mrc.limits(), &[4, 3, 2, 1]);
+        assert_eq!(mrc.iter_states().count(), 24);
+
+        assert!(MixedRadixCounter::<u8, 300>::factoradic().is_none());
+        assert!(MixedRadixCounter::<u8, 0>::factoradic().is_some());
+    }
+
+    #[test]
+    fn test_permutations() {
+        let mrc = MixedRadixCounter::<u8, 4>::factoradic().unwrap();
+
+        let mut previous = None;
+        for (rank, code) in mrc.iter_states().enumerate() {
+            let code =
+                MixedRadixCounter::try_from_limits_and_elements(*mrc.limits(), code).unwrap();
+            let permutation = code.to_permutation().unwrap();
+
+            // permutations are visited in lexicographic order
+            assert!(previous < Some(permutation));
+            previous = Some(permutation);
+
+            let ranked = MixedRadixCounter::from_permutation(&permutation).unwrap();
+            assert_eq!(ranked, code);
+            assert_eq!(ranked.to_scalar::<usize>(), Some(rank));
+        }
+
+        let code = MixedRadixCounter::from_scalar(*mrc.limits(), 23_u32)
+            .unwrap()
+            .0;
+        assert_eq!(code.to_permutation(), Some([3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn test_invalid_permutations() {
+        assert!(MixedRadixCounter::<u8, 3>::from_permutation(&[0, 1, 1]).is_none());
+        assert!(MixedRadixCounter::<u8, 3>::from_permutation(&[0, 1, 3]).is_none());
+
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 3, 3], [0, 2, 1]).unwrap();
+        assert_eq!(mrc.to_permutation(), None);
+    }
+}
@@ -0,0 +1,139 @@
+use core::ops::{Div, Rem};
+
+use num_traits::{CheckedAdd, CheckedMul, Zero};
+
+use crate::{InvalidValues, MixedRadixCounter};
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    /// Returns the scalar value that the current state represents, or `None` if that value
+    /// doesn't fit into `W`.
+    pub fn to_scalar<W>(&self) -> Option<W>
+    where
+        W: TryFrom<T> + Zero + CheckedAdd + CheckedMul,
+    {
+        let mut scalar = W::zero();
+        for (&element, &limit) in self.elements.iter().zip(self.limits.iter()) {
+            // the limit only matters once there is something to shift, which also allows
+            // limits that don't fit into `W` as long as all more significant elements are zero
+            if !scalar.is_zero() {
+                scalar = scalar.checked_mul(&W::try_from(limit).ok()?)?;
+            }
+            scalar = scalar.checked_add(&W::try_from(element).ok()?)?;
+        }
+        Some(scalar)
+    }
+
+    /// Creates a counter with the given limits, whose state represents `value`.
+    /// If `value` exceeds the capacity of the counter, the excess is returned alongside it,
+    /// in the same way that [`MixedRadixCounter::add`] reports its carry.
+    pub fn from_scalar<W>(limits: [T; E], value: W) -> Result<(Self, W), InvalidValues<T>>
+    where
+        T: TryFrom<W>,
+        W: TryFrom<T> + Zero + Copy,
+        W: Div<W, Output = W> + Rem<W, Output = W>,
+    {
+        let mut mrc = Self::try_from_limits(limits)?;
+
+        let mut rest = value;
+        for i in (0..E).rev() {
+            if rest.is_zero() {
+                break;
+            }
+
+            let element = match W::try_from(limits[i]) {
+                Ok(limit) => {
+                    let element = rest % limit;
+                    rest = rest / limit;
+                    element
+                }
+                // the limit is larger than anything `W` can hold, so the rest fits entirely
+                Err(_) => core::mem::replace(&mut rest, W::zero()),
+            };
+            let Ok(element) = T::try_from(element) else {
+                unreachable!("element is smaller than its limit, so it must fit into T");
+            };
+            mrc.elements[i] = element;
+        }
+
+        Ok((mrc, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_round_trip() {
+        let limits = [3_u8, 1, 4, 7];
+        let mut mrc = MixedRadixCounter::try_from_limits(limits).unwrap();
+        for scalar in 0_u16..84 {
+            assert_eq!(mrc.to_scalar::<u16>(), Some(scalar));
+            assert_eq!(
+                MixedRadixCounter::from_scalar(limits, scalar).unwrap(),
+                (mrc.clone(), 0)
+            );
+            mrc.increment();
+        }
+
+        assert_eq!(
+            MixedRadixCounter::from_scalar(limits, 84_u16).unwrap(),
+            (mrc.clone(), 1)
+        );
+        assert_eq!(
+            MixedRadixCounter::from_scalar(limits, 84 * 7 + 5_u16).unwrap(),
+            (
+                MixedRadixCounter::try_from_limits_and_elements(limits, [0, 0, 0, 5]).unwrap(),
+                7
+            )
+        );
+    }
+
+    #[test]
+    fn test_wide_scalar() {
+        let limits = [u64::MAX, 365, 24, 60, 60, 1000];
+        let mrc =
+            MixedRadixCounter::try_from_limits_and_elements(limits, [u64::MAX - 1, 0, 0, 0, 0, 1])
+                .unwrap();
+        let scalar = (u64::MAX as u128 - 1) * 365 * 24 * 60 * 60 * 1000 + 1;
+
+        assert_eq!(mrc.to_scalar::<u128>(), Some(scalar));
+        assert_eq!(mrc.to_scalar::<u64>(), None);
+        assert_eq!(
+            MixedRadixCounter::from_scalar(limits, scalar).unwrap(),
+            (mrc, 0)
+        );
+    }
+
+    #[test]
+    fn test_narrow_scalar() {
+        let limits = [1000_u16, 10];
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(limits, [0, 5]).unwrap();
+        assert_eq!(mrc.to_scalar::<u8>(), Some(5));
+        assert_eq!(
+            MixedRadixCounter::from_scalar(limits, 5_u8).unwrap(),
+            (mrc, 0)
+        );
+
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(limits, [25, 5]).unwrap();
+        assert_eq!(mrc.to_scalar::<u8>(), Some(255));
+        assert_eq!(
+            MixedRadixCounter::from_scalar(limits, 255_u8).unwrap(),
+            (mrc, 0)
+        );
+
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(limits, [25, 6]).unwrap();
+        assert_eq!(mrc.to_scalar::<u8>(), None);
+
+        let (mrc, excess) = MixedRadixCounter::from_scalar([u16::MAX], 255_u8).unwrap();
+        assert_eq!((*mrc, excess), ([255], 0));
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        assert!(MixedRadixCounter::from_scalar([3_u8, 0], 1_u8).is_err());
+    }
+}
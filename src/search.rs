@@ -0,0 +1,256 @@
+use core::iter::FusedIterator;
+use core::ops::Add;
+
+use num_traits::One;
+
+use crate::MixedRadixCounter;
+
+/// An iterator over the states of a [`MixedRadixCounter`] that skips whole subtrees of states.
+///
+/// Created by [`MixedRadixCounter::iter_pruned`].
+#[derive(Debug, Clone)]
+pub struct Pruned<T, const E: usize, F> {
+    counter: MixedRadixCounter<T, E>,
+    prune: F,
+    finished: bool,
+}
+
+/// An iterator over the states of a [`MixedRadixCounter`] whose prefixes are all accepted by a
+/// predicate.
+///
+/// Created by [`MixedRadixCounter::constrained`].
+#[derive(Debug, Clone)]
+pub struct Constrained<T, const E: usize, F> {
+    counter: MixedRadixCounter<T, E>,
+    accept: F,
+    /// The number of leading elements that have already been accepted.
+    depth: usize,
+    finished: bool,
+}
+
+impl<T, const E: usize> MixedRadixCounter<T, E>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    /// Returns an iterator over all states from the current one up to and including the
+    /// largest state, without modifying this counter.
+    ///
+    /// `prune` is called with every state before it is returned. If it returns `Some(index)`,
+    /// that state and every following state that shares its elements up to `index` are skipped,
+    /// see [`MixedRadixCounter::advance_at`].
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if `prune` returns an index that is out of bounds.
+    pub fn iter_pruned<F>(&self, prune: F) -> Pruned<T, E, F>
+    where
+        F: FnMut(&[T; E]) -> Option<usize>,
+    {
+        Pruned {
+            counter: self.clone(),
+            prune,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over all states from the current one up to and including the
+    /// largest state that are accepted by `accept`, without modifying this counter.
+    ///
+    /// `accept` is called with prefixes of a state, from the most significant element down to
+    /// some index. Once it rejects a prefix, no state that starts with that prefix is visited, so
+    /// `accept` has to reject a prefix only if no state with that prefix can be valid. A state is
+    /// returned if `accept` accepts it as a whole.
+    pub fn constrained<F>(&self, accept: F) -> Constrained<T, E, F>
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        Constrained {
+            counter: self.clone(),
+            accept,
+            depth: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<T, const E: usize, F> Iterator for Pruned<T, E, F>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    F: FnMut(&[T; E]) -> Option<usize>,
+{
+    type Item = [T; E];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let state = self.counter.elements;
+            match (self.prune)(&state) {
+                Some(index) => self.finished = self.counter.advance_at(index).is_some(),
+                None => {
+                    self.finished = self.counter.increment().is_some();
+                    return Some(state);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T, const E: usize, F> FusedIterator for Pruned<T, E, F>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    F: FnMut(&[T; E]) -> Option<usize>,
+{
+}
+
+impl<T, const E: usize, F> Constrained<T, E, F>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+{
+    /// Skips all states that share the elements up to `index`, and keeps those elements that
+    /// were left unchanged as the accepted prefix.
+    fn advance_at(&mut self, index: usize) {
+        if self.counter.advance_at(index).is_some() {
+            self.finished = true;
+            return;
+        }
+        // the element where the carry stopped is the only non-zero one at or below `index`
+        self.depth = self.counter.elements[..=index]
+            .iter()
+            .rposition(|&element| element != T::default())
+            .unwrap_or_default();
+    }
+}
+
+impl<T, const E: usize, F> Iterator for Constrained<T, E, F>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    F: FnMut(&[T]) -> bool,
+{
+    type Item = [T; E];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if E == 0 {
+            // there are no elements to advance, so the only state is checked on its own
+            let accepted = !self.finished && (self.accept)(&[]);
+            self.finished = true;
+            return accepted.then_some(self.counter.elements);
+        }
+
+        while !self.finished {
+            let index = self.depth;
+            if !(self.accept)(&self.counter.elements[..=index]) {
+                self.advance_at(index);
+            } else if index + 1 < E {
+                self.depth += 1;
+            } else {
+                let state = self.counter.elements;
+                self.advance_at(index);
+                return Some(state);
+            }
+        }
+        None
+    }
+}
+
+impl<T, const E: usize, F> FusedIterator for Constrained<T, E, F>
+where
+    T: One,
+    T: Add<Output = T> + Default,
+    T: PartialOrd<T> + Copy,
+    F: FnMut(&[T]) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_pruned() {
+        let mrc = MixedRadixCounter::try_from_limits([3_u8, 3, 2]).unwrap();
+
+        // skip everything that starts with 1, and every state whose second element is 2
+        let mut calls = 0;
+        let states = mrc.iter_pruned(|state| {
+            calls += 1;
+            match state {
+                [1, ..] => Some(0),
+                [_, 2, _] => Some(1),
+                _ => None,
+            }
+        });
+        assert!(states.eq([
+            [0, 0, 0],
+            [0, 0, 1],
+            [0, 1, 0],
+            [0, 1, 1],
+            [2, 0, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+            [2, 1, 1],
+        ]));
+        assert_eq!(calls, 11);
+    }
+
+    #[test]
+    fn test_pruned_without_pruning() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [1, 2]).unwrap();
+        assert!(mrc.iter_pruned(|_| None).eq(mrc.iter_states()));
+
+        let mut states = mrc.iter_pruned(|_| Some(0));
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pruned_out_of_bounds() {
+        let mrc = MixedRadixCounter::try_from_limits([3_u8, 4]).unwrap();
+        let _ = mrc.iter_pruned(|_| Some(2)).next();
+    }
+
+    #[test]
+    fn test_constrained() {
+        let mrc = MixedRadixCounter::try_from_limits([4_u8, 4, 4]).unwrap();
+
+        // strictly increasing elements, which can be decided on every prefix
+        let mut calls = 0;
+        let states = mrc.constrained(|prefix| {
+            calls += 1;
+            prefix.windows(2).all(|pair| pair[0] < pair[1])
+        });
+        assert!(states.eq([[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]));
+        assert!(calls < 64);
+
+        let filtered = mrc
+            .iter_states()
+            .filter(|state| state.iter().sum::<u8>() == 4);
+        assert!(mrc
+            .constrained(|prefix| {
+                let sum = prefix.iter().sum::<u8>();
+                sum <= 4 && (prefix.len() < 3 || sum == 4)
+            })
+            .eq(filtered));
+    }
+
+    #[test]
+    fn test_constrained_from_current_state() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements([3_u8, 4], [1, 2]).unwrap();
+        assert!(mrc.constrained(|_| true).eq(mrc.iter_states()));
+        assert_eq!(mrc.constrained(|_| false).next(), None);
+
+        let empty = MixedRadixCounter::<u8, 0>::try_from_limits([]).unwrap();
+        assert!(empty.constrained(|_| true).eq([[0_u8; 0]]));
+        assert_eq!(empty.constrained(|_| false).next(), None);
+    }
+}
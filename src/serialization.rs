@@ -0,0 +1,278 @@
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{InvalidValues, MixedRadixCounter};
+
+const FIELDS: &[&str] = &["elements", "limits"];
+
+impl<T, const E: usize> Serialize for MixedRadixCounter<T, E>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MixedRadixCounter", FIELDS.len())?;
+        state.serialize_field("elements", &Array(&self.elements))?;
+        state.serialize_field("limits", &Array(&self.limits))?;
+        state.end()
+    }
+}
+
+/// Deserializing runs the same validation as
+/// [`MixedRadixCounter::try_from_limits_and_elements`], so that an invalid counter can never be
+/// created this way.
+impl<'de, T, const E: usize> Deserialize<'de> for MixedRadixCounter<T, E>
+where
+    T: Deserialize<'de> + Default + Copy + PartialOrd<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("MixedRadixCounter", FIELDS, CounterVisitor(PhantomData))
+    }
+}
+
+// serde only implements (de)serialization for arrays up to a length of 32, so we (de)serialize
+// them as tuples ourselves.
+
+struct Array<'a, T, const E: usize>(&'a [T; E]);
+
+impl<T, const E: usize> Serialize for Array<'_, T, E>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(E)?;
+        for element in self.0 {
+            state.serialize_element(element)?;
+        }
+        state.end()
+    }
+}
+
+struct OwnedArray<T, const E: usize>([T; E]);
+
+impl<'de, T, const E: usize> Deserialize<'de> for OwnedArray<T, E>
+where
+    T: Deserialize<'de> + Default + Copy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(E, ArrayVisitor(PhantomData))
+    }
+}
+
+struct ArrayVisitor<T, const E: usize>(PhantomData<T>);
+
+impl<'de, T, const E: usize> Visitor<'de> for ArrayVisitor<T, E>
+where
+    T: Deserialize<'de> + Default + Copy,
+{
+    type Value = OwnedArray<T, E>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(formatter, "an array of length {E}")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = [T::default(); E];
+        for (i, element) in array.iter_mut().enumerate() {
+            *element = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<T>()?.is_some() {
+            return Err(Error::invalid_length(E + 1, &self));
+        }
+        Ok(OwnedArray(array))
+    }
+}
+
+enum Field {
+    Elements,
+    Limits,
+    Ignore,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("`elements` or `limits`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match value {
+            "elements" => Ok(Field::Elements),
+            "limits" => Ok(Field::Limits),
+            _ => Ok(Field::Ignore),
+        }
+    }
+}
+
+struct CounterVisitor<T, const E: usize>(PhantomData<T>);
+
+impl<T, const E: usize> CounterVisitor<T, E>
+where
+    T: Default + Copy + PartialOrd<T>,
+{
+    fn build<Err>(limits: [T; E], elements: [T; E]) -> Result<MixedRadixCounter<T, E>, Err>
+    where
+        Err: Error,
+    {
+        // `T` might not implement `Display`, so only the index is reported
+        MixedRadixCounter::try_from_limits_and_elements(limits, elements).map_err(|e| match e {
+            InvalidValues::ZeroLimit { index } => {
+                Error::custom(format_args!("limit {index} is zero"))
+            }
+            InvalidValues::OutOfRange { index, .. } => Error::custom(format_args!(
+                "element {index} is not smaller than its limit"
+            )),
+            InvalidValues::LengthMismatch { .. } | InvalidValues::IndexOutOfBounds { .. } => {
+                unreachable!("arrays always have the same length")
+            }
+        })
+    }
+}
+
+impl<'de, T, const E: usize> Visitor<'de> for CounterVisitor<T, E>
+where
+    T: Deserialize<'de> + Default + Copy + PartialOrd<T>,
+{
+    type Value = MixedRadixCounter<T, E>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("struct MixedRadixCounter")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let OwnedArray(elements) = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let OwnedArray(limits) = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Self::build(limits, elements)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut elements = None;
+        let mut limits = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Elements => {
+                    if elements.is_some() {
+                        return Err(Error::duplicate_field("elements"));
+                    }
+                    let OwnedArray(value) = map.next_value()?;
+                    elements = Some(value);
+                }
+                Field::Limits => {
+                    if limits.is_some() {
+                        return Err(Error::duplicate_field("limits"));
+                    }
+                    let OwnedArray(value) = map.next_value()?;
+                    limits = Some(value);
+                }
+                Field::Ignore => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let elements = elements.ok_or_else(|| Error::missing_field("elements"))?;
+        let limits = limits.ok_or_else(|| Error::missing_field("limits"))?;
+        Self::build(limits, elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MixedRadixCounter;
+
+    #[test]
+    fn test_round_trip() {
+        let mrc = MixedRadixCounter::try_from_limits_and_elements(
+            [u64::MAX, 365, 24, 60, 60],
+            [2, 73, 9, 36, 38],
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&mrc).unwrap();
+        assert_eq!(
+            json,
+            r#"{"elements":[2,73,9,36,38],"limits":[18446744073709551615,365,24,60,60]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<MixedRadixCounter<u64, 5>>(&json).unwrap(),
+            mrc
+        );
+    }
+
+    #[test]
+    fn test_large_array() {
+        let mrc = MixedRadixCounter::try_from_limits([2_u8; 40]).unwrap();
+        let json = serde_json::to_string(&mrc).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MixedRadixCounter<u8, 40>>(&json).unwrap(),
+            mrc
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid() {
+        for json in [
+            r#"{"elements":[2,3],"limits":[3,3]}"#,
+            r#"{"elements":[0,0],"limits":[3,0]}"#,
+            r#"{"elements":[0,0,0],"limits":[3,3]}"#,
+            r#"{"elements":[0],"limits":[3,3]}"#,
+            r#"{"elements":[0,0]}"#,
+            r#"{"elements":[0,0],"limits":[3,3],"limits":[3,3]}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<MixedRadixCounter<u8, 2>>(json).is_err(),
+                "{json}"
+            );
+        }
+
+        assert!(serde_json::from_str::<MixedRadixCounter<u8, 2>>(r#"[[2,2],[3,3]]"#).is_ok());
+        assert!(serde_json::from_str::<MixedRadixCounter<u8, 2>>(
+            r#"{"elements":[0,0],"limits":[3,3],"other":1}"#
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,372 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{Format, MixedRadixCounter, ParseError, ParseErrorKind};
+
+/// The frame rate of a [`Timecode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameRate {
+    Fps24,
+    Fps25,
+    Fps30,
+    Fps60,
+    /// 29.97 frames per second, where the frame numbers 0 and 1 are skipped at the start of
+    /// every minute, except for every tenth minute.
+    Fps2997DropFrame,
+}
+
+impl FrameRate {
+    /// Returns the number of frames that are counted per second, which is 30 for
+    /// [`FrameRate::Fps2997DropFrame`].
+    pub fn frames_per_second(self) -> u32 {
+        match self {
+            FrameRate::Fps24 => 24,
+            FrameRate::Fps25 => 25,
+            FrameRate::Fps30 | FrameRate::Fps2997DropFrame => 30,
+            FrameRate::Fps60 => 60,
+        }
+    }
+
+    pub fn is_drop_frame(self) -> bool {
+        self == FrameRate::Fps2997DropFrame
+    }
+
+    /// Returns the number of frames in 24 hours.
+    pub fn frames_per_day(self) -> u32 {
+        let frames = 24 * 60 * 60 * self.frames_per_second();
+        if self.is_drop_frame() {
+            // two frames are dropped in 9 out of 10 minutes
+            frames - 2 * (24 * 60 - 24 * 6)
+        } else {
+            frames
+        }
+    }
+
+    fn limits(self) -> [u32; 4] {
+        [24, 60, 60, self.frames_per_second()]
+    }
+}
+
+// Drop-frame timecode drops 18 frame numbers every 10 minutes, which are 10 * 60 * 30 - 18
+// frames long. Within those, the first minute is 60 * 30 frames long and the others are two
+// frames shorter.
+const DROP_FRAME_FRAMES_PER_10_MINUTES: u32 = 10 * 60 * 30 - 18;
+const DROP_FRAME_FRAMES_PER_MINUTE: u32 = 60 * 30 - 2;
+
+/// An SMPTE timecode `HH:MM:SS:FF` within 24 hours, which wraps around at midnight.
+///
+/// Drop-frame timecodes are written with `;` in front of the frames, as in `00:01:00;02`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timecode {
+    counter: MixedRadixCounter<u32, 4>,
+    rate: FrameRate,
+}
+
+impl Timecode {
+    /// Returns `None` if any element is out of range, or if the frame number is dropped at
+    /// this frame rate.
+    pub fn new(
+        rate: FrameRate,
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        frames: u32,
+    ) -> Option<Self> {
+        let counter = MixedRadixCounter::try_from_limits_and_elements(
+            rate.limits(),
+            [hours, minutes, seconds, frames],
+        )
+        .ok()?;
+        let timecode = Self { counter, rate };
+        if timecode.is_dropped() {
+            return None;
+        }
+        Some(timecode)
+    }
+
+    /// Creates the timecode of the frame with the given number, counted from `00:00:00:00`, or
+    /// returns `None` if `frames` isn't smaller than [`FrameRate::frames_per_day`].
+    pub fn from_frames(rate: FrameRate, frames: u32) -> Option<Self> {
+        if frames >= rate.frames_per_day() {
+            return None;
+        }
+
+        let mut frame_number = frames;
+        if rate.is_drop_frame() {
+            // add back the frame numbers that were dropped before this frame
+            let tens = frames / DROP_FRAME_FRAMES_PER_10_MINUTES;
+            let rest = frames % DROP_FRAME_FRAMES_PER_10_MINUTES;
+            frame_number += 18 * tens;
+            if rest > 1 {
+                frame_number += 2 * ((rest - 2) / DROP_FRAME_FRAMES_PER_MINUTE);
+            }
+        }
+
+        let (counter, _) = MixedRadixCounter::from_scalar(rate.limits(), frame_number).ok()?;
+        Some(Self { counter, rate })
+    }
+
+    /// Returns the number of the current frame, counted from `00:00:00:00`.
+    pub fn to_frames(&self) -> u32 {
+        let Some(frame_number) = self.counter.to_scalar::<u32>() else {
+            unreachable!("a day has less than u32::MAX frames");
+        };
+        if self.rate.is_drop_frame() {
+            let minutes = 60 * self.hours() + self.minutes();
+            frame_number - 2 * (minutes - minutes / 10)
+        } else {
+            frame_number
+        }
+    }
+
+    pub fn rate(&self) -> FrameRate {
+        self.rate
+    }
+
+    pub fn hours(&self) -> u32 {
+        self.counter[0]
+    }
+
+    pub fn minutes(&self) -> u32 {
+        self.counter[1]
+    }
+
+    pub fn seconds(&self) -> u32 {
+        self.counter[2]
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.counter[3]
+    }
+
+    /// Moves to the next frame, skipping dropped frame numbers, and returns the carry if the
+    /// timecode wrapped around at midnight.
+    pub fn increment(&mut self) -> Option<u32> {
+        let carry = self.counter.increment();
+        while self.is_dropped() {
+            self.counter.increment();
+        }
+        carry
+    }
+
+    /// Moves to the previous frame, skipping dropped frame numbers, and returns the borrow if
+    /// the timecode wrapped around at midnight.
+    pub fn decrement(&mut self) -> Option<u32> {
+        let borrow = self.counter.decrement();
+        while self.is_dropped() {
+            self.counter.decrement();
+        }
+        borrow
+    }
+
+    /// Moves `frames` frames forward, and returns how many days were wrapped around.
+    pub fn add(&mut self, frames: u32) -> Option<u32> {
+        let per_day = self.rate.frames_per_day();
+        let mut days = frames / per_day;
+        let mut total = self.to_frames() + frames % per_day;
+        if total >= per_day {
+            total -= per_day;
+            days += 1;
+        }
+        self.set_frames(total);
+        (days > 0).then_some(days)
+    }
+
+    /// Moves `frames` frames backward, and returns how many days were wrapped around.
+    pub fn sub(&mut self, frames: u32) -> Option<u32> {
+        let per_day = self.rate.frames_per_day();
+        let mut days = frames / per_day;
+        let current = self.to_frames();
+        let rest = frames % per_day;
+        let total = if rest > current {
+            days += 1;
+            current + per_day - rest
+        } else {
+            current - rest
+        };
+        self.set_frames(total);
+        (days > 0).then_some(days)
+    }
+
+    /// Parses a timecode like `01:02:03:04`, or `01:02:03;04` for drop-frame rates, and checks
+    /// it in the same way as [`Timecode::new`].
+    pub fn parse(s: &str, rate: FrameRate) -> Result<Self, ParseError> {
+        let error = |kind| ParseError { index: 3, kind };
+
+        let separator = if rate.is_drop_frame() { ';' } else { ':' };
+        let (time, frames) = s
+            .rsplit_once(separator)
+            .ok_or(error(ParseErrorKind::MissingSeparator))?;
+        let time = Format::<3>::new().parse(time, [24, 60, 60])?;
+        let frames = frames
+            .parse()
+            .map_err(|_| error(ParseErrorKind::InvalidElement))?;
+
+        Self::new(rate, time[0], time[1], time[2], frames).ok_or(error(ParseErrorKind::OutOfRange))
+    }
+
+    fn set_frames(&mut self, frames: u32) {
+        let Some(timecode) = Self::from_frames(self.rate, frames) else {
+            unreachable!("frames are always smaller than the frames per day");
+        };
+        *self = timecode;
+    }
+
+    fn is_dropped(&self) -> bool {
+        self.rate.is_drop_frame()
+            && self.frames() < 2
+            && self.seconds() == 0
+            && !self.minutes().is_multiple_of(10)
+    }
+}
+
+impl Display for Timecode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let separator = if self.rate.is_drop_frame() { ';' } else { ':' };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{separator}{:02}",
+            self.hours(),
+            self.minutes(),
+            self.seconds(),
+            self.frames()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::{FrameRate, ParseError, ParseErrorKind, Timecode};
+
+    const RATES: [FrameRate; 5] = [
+        FrameRate::Fps24,
+        FrameRate::Fps25,
+        FrameRate::Fps30,
+        FrameRate::Fps60,
+        FrameRate::Fps2997DropFrame,
+    ];
+
+    #[test]
+    fn test_drop_frame() {
+        let rate = FrameRate::Fps2997DropFrame;
+        assert!(Timecode::new(rate, 0, 1, 0, 0).is_none());
+        assert!(Timecode::new(rate, 0, 1, 0, 1).is_none());
+        assert!(Timecode::new(rate, 0, 10, 0, 0).is_some());
+
+        let mut timecode = Timecode::new(rate, 0, 0, 59, 29).unwrap();
+        assert_eq!(timecode.increment(), None);
+        assert_eq!(timecode.to_string(), "00:01:00;02");
+        assert_eq!(timecode.to_frames(), 1800);
+        assert_eq!(timecode.decrement(), None);
+        assert_eq!(timecode.to_string(), "00:00:59;29");
+
+        let mut timecode = Timecode::new(rate, 0, 9, 59, 29).unwrap();
+        assert_eq!(timecode.increment(), None);
+        assert_eq!(timecode.to_string(), "00:10:00;00");
+        assert_eq!(timecode.to_frames(), 17982);
+
+        // 29.97 drop-frame is off by only a few frames after a full hour of real time
+        let mut timecode = Timecode::new(rate, 0, 0, 0, 0).unwrap();
+        assert_eq!(timecode.add(107_892), None);
+        assert_eq!(timecode.to_string(), "01:00:00;00");
+        assert_eq!(rate.frames_per_day(), 2_589_408);
+    }
+
+    #[test]
+    fn test_frames_round_trip() {
+        for rate in RATES {
+            // the first 20 minutes contain every kind of drop-frame minute
+            let mut timecode = Timecode::from_frames(rate, 0).unwrap();
+            for frames in 0..20 * 60 * 30 {
+                assert_eq!(timecode.to_frames(), frames);
+                assert_eq!(
+                    Timecode::from_frames(rate, frames).as_ref(),
+                    Some(&timecode)
+                );
+                timecode.increment();
+            }
+
+            for frames in (0..rate.frames_per_day()).step_by(997) {
+                let timecode = Timecode::from_frames(rate, frames).unwrap();
+                assert_eq!(timecode.to_frames(), frames);
+            }
+            assert_eq!(Timecode::from_frames(rate, rate.frames_per_day()), None);
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        for rate in RATES {
+            let per_day = rate.frames_per_day();
+            let mut timecode = Timecode::new(rate, 23, 59, 59, 2).unwrap();
+            let start = timecode.to_frames();
+
+            assert_eq!(timecode.add(per_day - start), Some(1));
+            assert_eq!(timecode.to_frames(), 0);
+            assert_eq!(timecode.decrement(), Some(1));
+            assert_eq!(timecode.to_frames(), per_day - 1);
+
+            assert_eq!(timecode.sub(3 * per_day + 5), Some(3));
+            assert_eq!(timecode.to_frames(), per_day - 6);
+            assert_eq!(timecode.sub(per_day), Some(1));
+            assert_eq!(timecode.to_frames(), per_day - 6);
+            assert_eq!(timecode.add(12_345), Some(1));
+            assert_eq!(timecode.to_frames(), 12_345 - 6);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let timecode = Timecode::parse("01:02:03:04", FrameRate::Fps25).unwrap();
+        assert_eq!(
+            timecode,
+            Timecode::new(FrameRate::Fps25, 1, 2, 3, 4).unwrap()
+        );
+        assert_eq!(timecode.to_string(), "01:02:03:04");
+
+        let timecode = Timecode::parse("00:01:00;02", FrameRate::Fps2997DropFrame).unwrap();
+        assert_eq!(timecode.to_string(), "00:01:00;02");
+
+        for (s, rate, index, kind) in [
+            (
+                "00:01:00:02",
+                FrameRate::Fps2997DropFrame,
+                3,
+                ParseErrorKind::MissingSeparator,
+            ),
+            (
+                "00:01:00;01",
+                FrameRate::Fps2997DropFrame,
+                3,
+                ParseErrorKind::OutOfRange,
+            ),
+            (
+                "00:01:00:24",
+                FrameRate::Fps24,
+                3,
+                ParseErrorKind::OutOfRange,
+            ),
+            (
+                "00:01:00:x",
+                FrameRate::Fps24,
+                3,
+                ParseErrorKind::InvalidElement,
+            ),
+            (
+                "24:01:00:00",
+                FrameRate::Fps24,
+                0,
+                ParseErrorKind::OutOfRange,
+            ),
+        ] {
+            assert_eq!(
+                Timecode::parse(s, rate),
+                Err(ParseError { index, kind }),
+                "{s}"
+            );
+        }
+    }
+}
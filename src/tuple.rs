@@ -0,0 +1,358 @@
+use core::ops::Deref;
+
+use num_traits::{NumCast, PrimInt, Unsigned};
+
+use crate::{digits, InvalidValues};
+
+/// A tuple of unsigned integers that can be used as the elements and limits of a
+/// [`TupleCounter`]. Every position can have its own type.
+///
+/// This is implemented for tuples with up to 12 elements.
+pub trait DigitTuple: Copy {
+    fn zero() -> Self;
+
+    /// Element and limit are widened to `u128` in the error, since all positions have their own
+    /// type.
+    fn validate(limits: &Self, elements: &Self) -> Result<(), InvalidValues<u128>>;
+
+    /// Returns `true` if the elements wrapped around.
+    fn increment(elements: &mut Self, limits: &Self) -> bool;
+
+    /// Returns `true` if the elements wrapped around.
+    fn decrement(elements: &mut Self, limits: &Self) -> bool;
+
+    fn add<V>(elements: &mut Self, limits: &Self, value: V) -> Option<V>
+    where
+        V: PrimInt + Unsigned;
+
+    fn sub<V>(elements: &mut Self, limits: &Self, value: V) -> Option<V>
+    where
+        V: PrimInt + Unsigned;
+}
+
+/// A counter like [`MixedRadixCounter`](crate::MixedRadixCounter), whose elements are the
+/// fields of a tuple, so that every position can have its own type and limit.
+///
+/// The carry is passed from one position to the next in whatever type the caller used for the
+/// value, so positions may have a smaller type than the value that is added.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct TupleCounter<D> {
+    elements: D,
+    limits: D,
+}
+
+impl<D> Deref for TupleCounter<D> {
+    type Target = D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<D> TupleCounter<D>
+where
+    D: DigitTuple,
+{
+    pub fn try_from_limits(limits: D) -> Result<Self, InvalidValues<u128>> {
+        Self::try_from_limits_and_elements(limits, D::zero())
+    }
+
+    pub fn try_from_limits_and_elements(
+        limits: D,
+        elements: D,
+    ) -> Result<Self, InvalidValues<u128>> {
+        D::validate(&limits, &elements)?;
+        Ok(Self { elements, limits })
+    }
+
+    /// Returns `true` if the counter wrapped around to zero.
+    pub fn increment(&mut self) -> bool {
+        D::increment(&mut self.elements, &self.limits)
+    }
+
+    /// Returns `true` if the counter wrapped around to its largest state.
+    pub fn decrement(&mut self) -> bool {
+        D::decrement(&mut self.elements, &self.limits)
+    }
+
+    pub fn add<V>(&mut self, value: V) -> Option<V>
+    where
+        V: PrimInt + Unsigned,
+    {
+        D::add(&mut self.elements, &self.limits, value)
+    }
+
+    pub fn sub<V>(&mut self, value: V) -> Option<V>
+    where
+        V: PrimInt + Unsigned,
+    {
+        D::sub(&mut self.elements, &self.limits, value)
+    }
+}
+
+fn widen<T>(value: T) -> u128
+where
+    T: PrimInt + Unsigned,
+{
+    let Some(value) = value.to_u128() else {
+        unreachable!("every unsigned primitive integer fits into u128");
+    };
+    value
+}
+
+fn increment_element<T>(element: &mut T, limit: T) -> bool
+where
+    T: PrimInt + Unsigned,
+{
+    // this can't overflow since `element < limit <= T::MAX`
+    let sum = *element + T::one();
+    if sum < limit {
+        *element = sum;
+        return false;
+    }
+    *element = T::zero();
+    true
+}
+
+fn decrement_element<T>(element: &mut T, limit: T) -> bool
+where
+    T: PrimInt + Unsigned,
+{
+    if *element > T::zero() {
+        *element = *element - T::one();
+        return false;
+    }
+    *element = limit - T::one();
+    true
+}
+
+/// Splits `carry` into the part that is passed on to the next position and the part that is
+/// smaller than `limit`, without ever converting a value into a type that can't hold it.
+fn split_carry<T, V>(carry: V, limit: T) -> (V, T)
+where
+    T: PrimInt + Unsigned,
+    V: PrimInt + Unsigned,
+{
+    let (quotient, remainder) = match <V as NumCast>::from(limit) {
+        Some(limit) => (carry / limit, carry % limit),
+        // the limit is larger than anything `V` can hold, so the carry is smaller than the limit
+        None => (V::zero(), carry),
+    };
+    let Some(remainder) = <T as NumCast>::from(remainder) else {
+        unreachable!("remainder is smaller than the limit, so it must fit into T");
+    };
+    (quotient, remainder)
+}
+
+// See `digits::add` and `digits::sub` for why these can't overflow.
+
+fn add_element<T, V>(element: &mut T, limit: T, carry: V) -> V
+where
+    T: PrimInt + Unsigned,
+    V: PrimInt + Unsigned,
+{
+    let (carry, remainder) = split_carry(carry, limit);
+    let headroom = limit - *element;
+    if remainder < headroom {
+        *element = *element + remainder;
+        carry
+    } else {
+        *element = remainder - headroom;
+        carry + V::one()
+    }
+}
+
+fn sub_element<T, V>(element: &mut T, limit: T, borrow: V) -> V
+where
+    T: PrimInt + Unsigned,
+    V: PrimInt + Unsigned,
+{
+    let (borrow, remainder) = split_carry(borrow, limit);
+    if remainder <= *element {
+        *element = *element - remainder;
+        borrow
+    } else {
+        *element = limit - (remainder - *element);
+        borrow + V::one()
+    }
+}
+
+macro_rules! impl_digit_tuple {
+    ($($T:ident $i:tt),+; $($rev:tt),+) => {
+        impl<$($T),+> DigitTuple for ($($T,)+)
+        where
+            $($T: PrimInt + Unsigned),+
+        {
+            fn zero() -> Self {
+                ($($T::zero(),)+)
+            }
+
+            fn validate(limits: &Self, elements: &Self) -> Result<(), InvalidValues<u128>> {
+                $(
+                    digits::validate_element($i, widen(elements.$i), widen(limits.$i))?;
+                )+
+                Ok(())
+            }
+
+            fn increment(elements: &mut Self, limits: &Self) -> bool {
+                $(
+                    if !increment_element(&mut elements.$rev, limits.$rev) {
+                        return false;
+                    }
+                )+
+                true
+            }
+
+            fn decrement(elements: &mut Self, limits: &Self) -> bool {
+                $(
+                    if !decrement_element(&mut elements.$rev, limits.$rev) {
+                        return false;
+                    }
+                )+
+                true
+            }
+
+            fn add<V>(elements: &mut Self, limits: &Self, value: V) -> Option<V>
+            where
+                V: PrimInt + Unsigned,
+            {
+                let mut carry = value;
+                $(
+                    if carry.is_zero() {
+                        return None;
+                    }
+                    carry = add_element(&mut elements.$rev, limits.$rev, carry);
+                )+
+                if carry.is_zero() {
+                    None
+                } else {
+                    Some(carry)
+                }
+            }
+
+            fn sub<V>(elements: &mut Self, limits: &Self, value: V) -> Option<V>
+            where
+                V: PrimInt + Unsigned,
+            {
+                let mut borrow = value;
+                $(
+                    if borrow.is_zero() {
+                        return None;
+                    }
+                    borrow = sub_element(&mut elements.$rev, limits.$rev, borrow);
+                )+
+                if borrow.is_zero() {
+                    None
+                } else {
+                    Some(borrow)
+                }
+            }
+        }
+    };
+}
+
+impl_digit_tuple!(A 0; 0);
+impl_digit_tuple!(A 0, B 1; 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2; 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3; 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4; 4, 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4, F 5; 5, 4, 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6; 6, 5, 4, 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7; 7, 6, 5, 4, 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8; 8, 7, 6, 5, 4, 3, 2, 1, 0);
+impl_digit_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9; 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+impl_digit_tuple!(
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10;
+    10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+);
+impl_digit_tuple!(
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11;
+    11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{InvalidValues, MixedRadixCounter, TupleCounter};
+
+    #[test]
+    fn test_increment() {
+        let mut tc = TupleCounter::try_from_limits((2_u8, 4_u16, 3_u64)).unwrap();
+        let mut mrc = MixedRadixCounter::try_from_limits([2_u64, 4, 3]).unwrap();
+
+        for _ in 0..23 {
+            assert!(!tc.increment());
+            assert!(mrc.increment().is_none());
+            assert_eq!(*tc, (mrc[0] as u8, mrc[1] as u16, mrc[2]));
+        }
+        assert!(tc.increment());
+        assert_eq!(*tc, (0, 0, 0));
+
+        assert!(tc.decrement());
+        assert_eq!(*tc, (1, 3, 2));
+        assert!(!tc.decrement());
+        assert_eq!(*tc, (1, 3, 1));
+    }
+
+    #[test]
+    fn test_large_add() {
+        let mut tc =
+            TupleCounter::try_from_limits((u64::MAX, 365_u16, 24_u8, 60_u8, 60_u8)).unwrap();
+
+        assert_eq!(tc.add(69_413_798_u32), None);
+        assert_eq!(*tc, (2, 73, 9, 36, 38));
+
+        assert_eq!(tc.sub(69_413_798_u64), None);
+        assert_eq!(*tc, (0, 0, 0, 0, 0));
+
+        assert_eq!(tc.sub(1_u8), Some(1));
+        assert_eq!(*tc, (u64::MAX - 1, 364, 23, 59, 59));
+    }
+
+    #[test]
+    fn test_matches_mixed_radix_counter() {
+        let limits = [7_u64, 300, 5, 256];
+        for value in (0..=u32::MAX).step_by(65_537) {
+            let mut tc = TupleCounter::try_from_limits((7_u8, 300_u16, 5_u8, 256_u16)).unwrap();
+            let mut mrc = MixedRadixCounter::try_from_limits(limits).unwrap();
+
+            let carry = tc.add(value);
+            assert_eq!(carry.map(u64::from), mrc.add(value as u64));
+            assert_eq!(
+                *tc,
+                (mrc[0] as u8, mrc[1] as u16, mrc[2] as u8, mrc[3] as u16)
+            );
+
+            let borrow = tc.sub(value);
+            assert_eq!(borrow.map(u64::from), mrc.sub(value as u64));
+            assert_eq!(*tc, (0, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_carry_larger_than_element_type() {
+        let mut tc = TupleCounter::try_from_limits((u8::MAX, 2_u8)).unwrap();
+        assert_eq!(tc.add(u64::MAX), Some(u64::MAX / 510));
+        assert_eq!(*tc, (((u64::MAX % 510) / 2) as u8, (u64::MAX % 2) as u8));
+
+        let mut tc = TupleCounter::try_from_limits((u64::MAX,)).unwrap();
+        assert_eq!(tc.add(u8::MAX), None);
+        assert_eq!(*tc, (u8::MAX as u64,));
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert_eq!(
+            TupleCounter::try_from_limits((2_u8, 0_u16)),
+            Err(InvalidValues::ZeroLimit { index: 1 })
+        );
+        assert_eq!(
+            TupleCounter::try_from_limits_and_elements((2_u8, 3_u16), (1, 3)),
+            Err(InvalidValues::OutOfRange {
+                index: 1,
+                element: 3,
+                limit: 3
+            })
+        );
+        assert!(TupleCounter::try_from_limits_and_elements((2_u8, 3_u16), (1, 2)).is_ok());
+    }
+}